@@ -0,0 +1,70 @@
+use aoc_2024_01::incremental::{IncrementalLists, Side};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [1_000, 4_000, 16_000];
+
+/// Deterministic values spread over most of the `i32` range, alternating between the lists.
+fn random_stream(size: usize) -> Vec<(Side, i32)> {
+    let mut state = 1_u64;
+
+    (0..size)
+        .map(|i| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            let side = if i % 2 == 0 { Side::Left } else { Side::Right };
+
+            (side, (state >> 34) as i32)
+        })
+        .collect()
+}
+
+/// Increasing values going left, right, right, left, so the walk changes sign at every other
+/// value, the worst case of the distances sum update.
+fn alternating_stream(size: usize) -> Vec<(Side, i32)> {
+    (0..size)
+        .map(|i| {
+            let side = if matches!(i % 4, 0 | 3) {
+                Side::Left
+            } else {
+                Side::Right
+            };
+
+            (side, i as i32)
+        })
+        .collect()
+}
+
+fn insert_all(stream: &[(Side, i32)]) -> i64 {
+    let mut lists = IncrementalLists::new();
+
+    for &(side, value) in stream {
+        lists.insert(side, value);
+    }
+
+    lists.distances_sum()
+}
+
+fn bench_incremental(c: &mut Criterion) {
+    let mut group = c.benchmark_group("incremental");
+
+    group.sample_size(10);
+
+    for size in SIZES {
+        for (name, stream) in [
+            ("random", random_stream(size)),
+            ("alternating", alternating_stream(size)),
+        ] {
+            group.bench_with_input(BenchmarkId::new(name, size), &stream, |b, stream| {
+                b.iter(|| insert_all(black_box(stream)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_incremental);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+use aoc_2024_01::similarity;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [1_000, 10_000, 50_000];
+
+/// The previous implementation, scanning `rights` for every left value.
+fn quadratic_similarity(lefts: &[i32], rights: &[i32]) -> i64 {
+    lefts.iter().fold(0, |acc, left| {
+        let single_similarity_score = rights.iter().filter(|&right| right == left).count();
+
+        acc + i64::from(*left) * (single_similarity_score as i64)
+    })
+}
+
+/// Deterministic location ids below 1000, so ids repeat like in the real input.
+fn location_ids(size: usize, seed: u64) -> Vec<i32> {
+    let mut state = seed;
+
+    (0..size)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            ((state >> 33) % 1_000) as i32
+        })
+        .collect()
+}
+
+fn bench_similarity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("similarity");
+
+    group.sample_size(10);
+
+    for size in SIZES {
+        let lefts = location_ids(size, 1);
+        let rights = location_ids(size, 2);
+
+        assert_eq!(
+            quadratic_similarity(&lefts, &rights),
+            similarity(&lefts, &rights)
+        );
+
+        group.bench_with_input(BenchmarkId::new("counting", size), &size, |b, _| {
+            b.iter(|| similarity(black_box(&lefts), black_box(&rights)))
+        });
+        group.bench_with_input(BenchmarkId::new("quadratic", size), &size, |b, _| {
+            b.iter(|| quadratic_similarity(black_box(&lefts), black_box(&rights)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_similarity);
+criterion_main!(benches);
@@ -0,0 +1,461 @@
+//! Left and right lists updated one value at a time, as when they arrive as a stream.
+
+use std::cmp::Ordering;
+
+/// One of the two lists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Side {
+    /// The left list.
+    Left,
+    /// The right list.
+    Right,
+}
+
+/// Both lists kept in a single order-statistics tree keyed by value, so they never need sorting
+/// again.
+///
+/// Walking the values in order, let the walk be the number of left values minus the number of
+/// right values seen so far. The distances sum is the integral of the absolute walk over the
+/// values, up to the largest paired value of the longest list, plus the distances from there to
+/// the larger values of the shortest list. Each tree node keeps the extent of the walk over its
+/// subtree, so whole subtrees where the walk keeps its sign are summed at once.
+///
+/// Inserting and removing take `O(log n)` to update the tree and the similarity score. They also
+/// recompute the distances sum, which takes `O(log n)` per sign change of the walk: about
+/// `O(√n log n)` for random values, but `O(n)` at worst, when the walk changes sign at almost
+/// every value. The `incremental` bench measures both cases.
+#[derive(Clone, Debug, Default)]
+pub struct IncrementalLists {
+    root: Tree,
+    lens: [usize; 2],
+    distances_sum: i64,
+    similarity_score: i64,
+    priorities: u64,
+}
+
+type Tree = Option<Box<Node>>;
+
+/// A value with how many times each list holds it, and the aggregates of its subtree.
+#[derive(Clone, Debug)]
+struct Node {
+    value: i32,
+    counts: [usize; 2],
+    priority: u64,
+    left: Tree,
+    right: Tree,
+    sizes: [usize; 2],
+    sums: [i128; 2],
+    min_value: i32,
+    max_value: i32,
+    min_walk: i64,
+    max_walk: i64,
+}
+
+impl Node {
+    fn new(value: i32, priority: u64) -> Self {
+        Self {
+            value,
+            counts: [0, 0],
+            priority,
+            left: None,
+            right: None,
+            sizes: [0, 0],
+            sums: [0, 0],
+            min_value: value,
+            max_value: value,
+            min_walk: 0,
+            max_walk: 0,
+        }
+    }
+
+    /// The walk over the whole subtree.
+    fn walk(&self) -> i64 {
+        self.sizes[0] as i64 - self.sizes[1] as i64
+    }
+
+    /// Recomputes the aggregates of the subtree from the children's.
+    fn update(&mut self) {
+        let (left, right) = (self.left.as_deref(), self.right.as_deref());
+        let own_walk = left.map_or(0, Node::walk) + self.counts[0] as i64 - self.counts[1] as i64;
+
+        for side in 0..2 {
+            self.sizes[side] = self.counts[side]
+                + left.map_or(0, |left| left.sizes[side])
+                + right.map_or(0, |right| right.sizes[side]);
+            self.sums[side] = i128::from(self.value) * self.counts[side] as i128
+                + left.map_or(0, |left| left.sums[side])
+                + right.map_or(0, |right| right.sums[side]);
+        }
+
+        self.min_value = left.map_or(self.value, |left| left.min_value);
+        self.max_value = right.map_or(self.value, |right| right.max_value);
+        self.min_walk = own_walk
+            .min(left.map_or(own_walk, |left| left.min_walk))
+            .min(right.map_or(own_walk, |right| own_walk + right.min_walk));
+        self.max_walk = own_walk
+            .max(left.map_or(own_walk, |left| left.max_walk))
+            .max(right.map_or(own_walk, |right| own_walk + right.max_walk));
+    }
+}
+
+/// Splits `tree` into the values below `value` and the others.
+fn split(tree: Tree, value: i64) -> (Tree, Tree) {
+    let Some(mut node) = tree else {
+        return (None, None);
+    };
+
+    if i64::from(node.value) < value {
+        let (left, right) = split(node.right.take(), value);
+
+        node.right = left;
+        node.update();
+
+        (Some(node), right)
+    } else {
+        let (left, right) = split(node.left.take(), value);
+
+        node.left = right;
+        node.update();
+
+        (left, Some(node))
+    }
+}
+
+/// Joins `left` and `right`, all of whose values are larger.
+fn merge(left: Tree, right: Tree) -> Tree {
+    match (left, right) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update();
+
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update();
+
+                Some(right)
+            }
+        }
+    }
+}
+
+/// Returns the `rank`th smallest value of the `side` list, counting from 0.
+fn select(tree: &Tree, side: usize, rank: usize) -> i32 {
+    let node = tree.as_deref().expect("rank is below the list length");
+    let left_size = node.left.as_ref().map_or(0, |left| left.sizes[side]);
+
+    if rank < left_size {
+        select(&node.left, side, rank)
+    } else if rank < left_size + node.counts[side] {
+        node.value
+    } else {
+        select(&node.right, side, rank - left_size - node.counts[side])
+    }
+}
+
+/// Sums the distances from `value` to the larger values of the `side` list, descending a single
+/// path of the tree.
+fn distances_above(tree: &Tree, side: usize, value: i64) -> i128 {
+    let Some(node) = tree.as_deref() else {
+        return 0;
+    };
+
+    if i64::from(node.value) <= value {
+        return distances_above(&node.right, side, value);
+    }
+
+    let distances = |count: usize, sum: i128| sum - i128::from(value) * count as i128;
+    let own_distances = distances(
+        node.counts[side],
+        i128::from(node.value) * node.counts[side] as i128,
+    );
+    let right_distances = node
+        .right
+        .as_deref()
+        .map_or(0, |right| distances(right.sizes[side], right.sums[side]));
+
+    own_distances + right_distances + distances_above(&node.left, side, value)
+}
+
+/// Integrates the absolute walk over the values of `tree` below `cutoff`, the walk starting at
+/// `start` and the next value after the subtree being `next`.
+fn walk_integral(tree: &Tree, start: i64, next: i64, cutoff: i64) -> i128 {
+    let Some(node) = tree.as_deref() else {
+        return 0;
+    };
+
+    if i64::from(node.min_value) >= cutoff {
+        return 0;
+    }
+
+    let next = next.min(cutoff);
+
+    if i64::from(node.max_value) < cutoff
+        && (start + node.min_walk >= 0 || start + node.max_walk <= 0)
+    {
+        let sign = if start + node.min_walk >= 0 { 1 } else { -1 };
+        let end = start + node.walk();
+
+        // Summing walk × gap by parts leaves the end and start terms and each value times its
+        // step of the walk.
+        return sign
+            * (i128::from(end) * i128::from(next)
+                - i128::from(start) * i128::from(node.min_value)
+                - (node.sums[0] - node.sums[1]));
+    }
+
+    let own_walk = start + node.left.as_deref().map_or(0, Node::walk) + node.counts[0] as i64
+        - node.counts[1] as i64;
+    let own_next = node
+        .right
+        .as_deref()
+        .map_or(next, |right| i64::from(right.min_value))
+        .min(cutoff);
+    let own_integral =
+        i128::from(own_walk.abs()) * i128::from((own_next - i64::from(node.value)).max(0));
+
+    walk_integral(&node.left, start, node.value.into(), cutoff)
+        + own_integral
+        + walk_integral(&node.right, own_walk, next, cutoff)
+}
+
+impl IncrementalLists {
+    /// Creates empty lists.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how many times `value` is in the `side` list.
+    pub fn count(&self, side: Side, value: i32) -> usize {
+        let mut tree = &self.root;
+
+        while let Some(node) = tree {
+            tree = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Equal => return node.counts[side as usize],
+                Ordering::Greater => &node.right,
+            };
+        }
+
+        0
+    }
+
+    /// Returns the number of values in the `side` list.
+    pub fn len(&self, side: Side) -> usize {
+        self.lens[side as usize]
+    }
+
+    /// Tells whether both lists are empty.
+    pub fn is_empty(&self) -> bool {
+        self.lens == [0, 0]
+    }
+
+    /// Adds `value` to the `side` list.
+    pub fn insert(&mut self, side: Side, value: i32) {
+        let other_count = self.count(side.other(), value);
+
+        self.update_count(side, value, |count| count + 1);
+        self.lens[side as usize] += 1;
+        self.similarity_score = self
+            .similarity_score
+            .checked_add(similarity_term(value, other_count))
+            .expect("similarity score overflows i64");
+        self.distances_sum = self.paired_distances_sum();
+    }
+
+    /// Removes one occurrence of `value` from the `side` list, returning whether it was there.
+    pub fn remove(&mut self, side: Side, value: i32) -> bool {
+        if self.count(side, value) == 0 {
+            return false;
+        }
+
+        let other_count = self.count(side.other(), value);
+
+        self.update_count(side, value, |count| count - 1);
+        self.lens[side as usize] -= 1;
+        self.similarity_score -= similarity_term(value, other_count);
+        self.distances_sum = self.paired_distances_sum();
+
+        true
+    }
+
+    /// Sums the distances between the sorted lists, like [`crate::distances_sum`].
+    pub fn distances_sum(&self) -> i64 {
+        self.distances_sum
+    }
+
+    /// Returns the similarity score, like [`crate::similarity_score`].
+    pub fn similarity_score(&self) -> i64 {
+        self.similarity_score
+    }
+
+    /// Replaces how many times `value` is in the `side` list, dropping the node of a value left in
+    /// neither list.
+    fn update_count(&mut self, side: Side, value: i32, update: impl FnOnce(usize) -> usize) {
+        let (lower, rest) = split(self.root.take(), value.into());
+        let (node, higher) = split(rest, i64::from(value) + 1);
+        let mut node = node.unwrap_or_else(|| {
+            self.priorities += 1;
+
+            Box::new(Node::new(value, priority(self.priorities)))
+        });
+
+        node.counts[side as usize] = update(node.counts[side as usize]);
+        node.update();
+
+        let node = (node.counts != [0, 0]).then_some(node);
+
+        self.root = merge(merge(lower, node), higher);
+    }
+
+    /// Computes the distances sum from the tree.
+    ///
+    /// Panics if the sum overflows `i64`.
+    fn paired_distances_sum(&self) -> i64 {
+        let (longest, shortest) = if self.lens[0] >= self.lens[1] {
+            (0, 1)
+        } else {
+            (1, 0)
+        };
+        let pairs_amount = self.lens[shortest];
+
+        if pairs_amount == 0 {
+            return 0;
+        }
+
+        let cutoff = select(&self.root, longest, pairs_amount - 1).into();
+        let distances_sum = walk_integral(&self.root, 0, cutoff, cutoff)
+            + distances_above(&self.root, shortest, cutoff);
+
+        i64::try_from(distances_sum).expect("distances sum overflows i64")
+    }
+}
+
+impl Side {
+    fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Mixes `seed` into a pseudorandom tree priority, splitmix64 style.
+fn priority(seed: u64) -> u64 {
+    let mut z = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
+
+fn similarity_term(value: i32, other_count: usize) -> i64 {
+    i64::from(value)
+        .checked_mul(other_count as i64)
+        .expect("similarity score overflows i64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{distances, similarity};
+
+    #[test]
+    fn test_incremental_lists() {
+        let mut lists = IncrementalLists::new();
+
+        for (left, right) in [(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)] {
+            lists.insert(Side::Left, left);
+            lists.insert(Side::Right, right);
+        }
+
+        assert_eq!((11, 31), (lists.distances_sum(), lists.similarity_score()));
+        assert!(lists.remove(Side::Right, 3));
+        assert!(!lists.remove(Side::Left, 7));
+        assert_eq!(2, lists.count(Side::Right, 3));
+        assert_eq!((6, 5), (lists.len(Side::Left), lists.len(Side::Right)));
+        assert_eq!(
+            (
+                distances(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 9, 3]),
+                similarity(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 9, 3])
+            ),
+            (lists.distances_sum(), lists.similarity_score())
+        );
+    }
+
+    #[test]
+    fn test_incremental_lists_against_batch_functions() {
+        let mut lists = IncrementalLists::new();
+        let (mut lefts, mut rights) = (vec![], vec![]);
+        let mut state = 7_u64;
+
+        for _ in 0..2_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            let value = ((state >> 33) % 50) as i32;
+            let (side, values) = match (state >> 20) & 1 {
+                0 => (Side::Left, &mut lefts),
+                _ => (Side::Right, &mut rights),
+            };
+
+            if (state >> 40).is_multiple_of(3) {
+                let position = values.iter().position(|&v| v == value);
+
+                assert_eq!(position.is_some(), lists.remove(side, value));
+
+                if let Some(position) = position {
+                    values.swap_remove(position);
+                }
+            } else {
+                lists.insert(side, value);
+                values.push(value);
+            }
+
+            assert_eq!(distances(&lefts, &rights), lists.distances_sum());
+            assert_eq!(similarity(&lefts, &rights), lists.similarity_score());
+        }
+    }
+
+    #[test]
+    fn test_incremental_lists_with_distant_values() {
+        let mut lists = IncrementalLists::new();
+        let (mut lefts, mut rights) = (vec![], vec![]);
+        let mut state = 11_u64;
+
+        for i in 0..1_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+
+            let value = match i % 10 {
+                0 => i32::MIN,
+                1 => i32::MAX,
+                _ => (state >> 32) as i32,
+            };
+            let (side, values) = match i % 3 {
+                0 => (Side::Right, &mut rights),
+                _ => (Side::Left, &mut lefts),
+            };
+
+            lists.insert(side, value);
+            values.push(value);
+
+            assert_eq!(distances(&lefts, &rights), lists.distances_sum());
+        }
+
+        while lefts.len() > 200 {
+            assert!(lists.remove(Side::Left, lefts.swap_remove(0)));
+            assert_eq!(distances(&lefts, &rights), lists.distances_sum());
+        }
+
+        assert_eq!(
+            (lefts.len(), rights.len()),
+            (lists.len(Side::Left), lists.len(Side::Right))
+        );
+    }
+}
@@ -0,0 +1,243 @@
+//! Day 1: Historian Hysteria, <https://adventofcode.com/2024/day/1>.
+#![warn(missing_docs)]
+
+use aoc_common::{error::parse_integer, ParseError, ParseErrorKind, Solution};
+use pairing::Absolute;
+use std::collections::HashMap;
+
+pub mod incremental;
+pub mod pairing;
+
+/// Parses the two location id lists side by side, one pair per line.
+pub struct Day01;
+
+/// Which columns of the whitespace separated input hold the left and right lists.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Columns {
+    count: usize,
+    left: usize,
+    right: usize,
+}
+
+impl Columns {
+    /// Every line has `count` columns, the lists being in the `left` and `right` ones, counting
+    /// from 0. The other columns are not parsed.
+    ///
+    /// Panics if `left` or `right` is not below `count`.
+    pub fn new(count: usize, left: usize, right: usize) -> Self {
+        assert!(
+            left < count && right < count,
+            "columns {left} and {right} must be below {count}"
+        );
+
+        Self { count, left, right }
+    }
+}
+
+impl Default for Columns {
+    /// The puzzle input, where each line is a left and a right id.
+    fn default() -> Self {
+        Self::new(2, 0, 1)
+    }
+}
+
+/// The left and right lists along with the input line each pair of values comes from.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NumberedLists {
+    /// The left list.
+    pub lefts: Vec<i32>,
+    /// The right list.
+    pub rights: Vec<i32>,
+    /// The 1-based input line of each pair of values, blank lines included in the count.
+    pub line_numbers: Vec<usize>,
+}
+
+/// Parses the left and right lists line by line, skipping blank lines. A line with another number
+/// of columns than `columns` expects is an error.
+pub fn lefts_and_rights(
+    input_contents: &str,
+    columns: Columns,
+) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let lists = numbered_lefts_and_rights(input_contents, columns)?;
+
+    Ok((lists.lefts, lists.rights))
+}
+
+/// Parses the lists like [`lefts_and_rights`], recording the line number of each pair of values.
+pub fn numbered_lefts_and_rights(
+    input_contents: &str,
+    columns: Columns,
+) -> Result<NumberedLists, ParseError> {
+    let mut lists = NumberedLists::default();
+
+    for (line_index, line) in input_contents.lines().enumerate() {
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+
+        if tokens.is_empty() {
+            continue;
+        }
+
+        if tokens.len() != columns.count {
+            let kind = ParseErrorKind::ColumnCount {
+                expected: columns.count,
+                actual: tokens.len(),
+            };
+            let location = tokens
+                .get(columns.count)
+                .copied()
+                .unwrap_or(&line[line.len()..]);
+
+            return Err(ParseError::at(input_contents, location, kind));
+        }
+
+        lists
+            .lefts
+            .push(parse_integer(input_contents, tokens[columns.left])?);
+        lists
+            .rights
+            .push(parse_integer(input_contents, tokens[columns.right])?);
+        lists.line_numbers.push(line_index + 1);
+    }
+
+    Ok(lists)
+}
+
+/// Sums the [`Absolute`] distances between the sorted `lefts` and `rights`.
+///
+/// Panics if the sum overflows `i64`.
+pub fn distances(lefts: &[i32], rights: &[i32]) -> i64 {
+    i64::try_from(pairing::distances_total(lefts, rights, &Absolute))
+        .expect("distances sum overflows i64")
+}
+
+/// Sums each of `lefts` multiplied by how many times it appears in `rights`, counting `rights`
+/// once up front so it runs in linear time.
+///
+/// Panics if the sum overflows `i64`.
+pub fn similarity(lefts: &[i32], rights: &[i32]) -> i64 {
+    let mut rights_counts = HashMap::with_capacity(rights.len());
+
+    for right in rights {
+        *rights_counts.entry(right).or_insert(0_i64) += 1;
+    }
+
+    lefts
+        .iter()
+        .try_fold(0_i64, |acc, left| {
+            let count = rights_counts.get(left).copied().unwrap_or(0);
+
+            acc.checked_add(i64::from(*left).checked_mul(count)?)
+        })
+        .expect("similarity score overflows i64")
+}
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Error = ParseError;
+    type Answer = i64;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        lefts_and_rights(input_contents, Columns::default())
+    }
+
+    fn part_one((lefts, rights): &Self::Input) -> Self::Answer {
+        distances(lefts, rights)
+    }
+
+    fn part_two((lefts, rights): &Self::Input) -> Self::Answer {
+        similarity(lefts, rights)
+    }
+}
+
+/// Sums the distances between the sorted left and right lists.
+pub fn distances_sum(input_contents: &str) -> Result<i64, ParseError> {
+    Ok(Day01::part_one(&Day01::parse(input_contents)?))
+}
+
+/// Sums each left id multiplied by how many times it appears in the right list.
+pub fn similarity_score(input_contents: &str) -> Result<i64, ParseError> {
+    Ok(Day01::part_two(&Day01::parse(input_contents)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "3   4
+    4   3
+    2   5
+    1   3
+    3   9
+    3   3
+    ";
+
+    #[test]
+    fn test_distances_sum() {
+        let expected = 11;
+        let actual = distances_sum(TEST_INPUT_CONTENTS).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_similarity_score() {
+        let expected = 31;
+        let actual = similarity_score(TEST_INPUT_CONTENTS).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_distances_sum_with_uneven_columns() {
+        let expected = "line 2, column 9: line has 3 columns, expected 2";
+        let actual = distances_sum("3   4\n4   3   7\n2   5\n")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(expected, actual);
+
+        let expected = "line 2, column 2: line has 1 columns, expected 2";
+        let actual = distances_sum("3   4\n4\n2   5\n").unwrap_err().to_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_totals_beyond_i32() {
+        let input_contents = "-2000000000   2000000000
+-2000000000   2000000000
+2000000000   2000000000
+";
+
+        assert_eq!(Ok(8_000_000_000), distances_sum(input_contents));
+        assert_eq!(Ok(6_000_000_000), similarity_score(input_contents));
+    }
+
+    #[test]
+    fn test_lefts_and_rights_with_chosen_columns() {
+        let expected = (vec![4, 3], vec![1, 2]);
+        let actual = lefts_and_rights("a 1 x 4\nb 2 y 3\n", Columns::new(4, 3, 1)).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_numbered_lefts_and_rights_with_blank_lines() {
+        let expected = NumberedLists {
+            lefts: vec![3, 4],
+            rights: vec![4, 3],
+            line_numbers: vec![2, 4],
+        };
+        let actual = numbered_lefts_and_rights("\n3   4\n\n4   3\n", Columns::default()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_similarity_with_unmatched_and_repeated_ids() {
+        let expected = 7 + 5 * 3 + 7;
+        let actual = similarity(&[7, 5, 8, 7], &[5, 7, 5, 1, 5]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_distances_sum_with_malformed_input() {
+        let expected = "line 2, column 5: invalid integer `3x`: invalid digit found in string";
+        let actual = distances_sum("3   4\n4   3x\n").unwrap_err().to_string();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,242 @@
+//! Pairs the smallest left value with the smallest right value, the second smallest with the
+//! second smallest and so on, measuring each pair with a selectable [`Metric`].
+
+use crate::NumberedLists;
+
+/// Measures the distance between a left and a right value. Any `Fn(i32, i32) -> D` closure is a
+/// metric too.
+pub trait Metric {
+    /// The distance type, summed by [`distances_total`].
+    type Distance: Summable;
+
+    /// Returns the distance between `left` and `right`.
+    fn distance(&self, left: i32, right: i32) -> Self::Distance;
+}
+
+/// A distance type [`distances_total`] can sum without overflowing silently.
+pub trait Summable {
+    /// The type of a sum of distances, wider than the distances where they are bounded.
+    type Total: Copy;
+
+    /// The sum of no distances.
+    const ZERO: Self::Total;
+
+    /// Adds the distance to `total`, returning `None` on overflow.
+    fn checked_add_to(self, total: Self::Total) -> Option<Self::Total>;
+}
+
+/// `|left - right|`, the puzzle metric.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Absolute;
+
+/// `(left - right)²`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Squared;
+
+/// `|left - right|` as a percentage of the largest magnitude of the two, 0 when both are 0.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Relative;
+
+/// A left value matched with a right value of the same rank.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pairing<D> {
+    /// The left value.
+    pub left: i32,
+    /// The right value.
+    pub right: i32,
+    /// The 1-based input line `left` comes from.
+    pub left_line: usize,
+    /// The 1-based input line `right` comes from.
+    pub right_line: usize,
+    /// The distance between `left` and `right`.
+    pub distance: D,
+}
+
+impl Metric for Absolute {
+    type Distance = u32;
+
+    fn distance(&self, left: i32, right: i32) -> Self::Distance {
+        left.abs_diff(right)
+    }
+}
+
+impl Metric for Squared {
+    type Distance = u64;
+
+    fn distance(&self, left: i32, right: i32) -> Self::Distance {
+        u64::from(left.abs_diff(right)).pow(2)
+    }
+}
+
+impl Metric for Relative {
+    type Distance = f64;
+
+    fn distance(&self, left: i32, right: i32) -> Self::Distance {
+        let largest = left.unsigned_abs().max(right.unsigned_abs());
+
+        if largest == 0 {
+            return 0.0;
+        }
+
+        f64::from(left.abs_diff(right)) / f64::from(largest) * 100.0
+    }
+}
+
+impl<F, D> Metric for F
+where
+    F: Fn(i32, i32) -> D,
+    D: Summable,
+{
+    type Distance = D;
+
+    fn distance(&self, left: i32, right: i32) -> Self::Distance {
+        self(left, right)
+    }
+}
+
+fn sorted_indices(values: &[i32]) -> Vec<usize> {
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+
+    indices.sort_by_key(|&i| values[i]);
+
+    indices
+}
+
+/// Pairs the lists by rank, equal values keeping their list order. The longest list's extra values
+/// are left out.
+pub fn pairings<M: Metric>(lists: &NumberedLists, metric: &M) -> Vec<Pairing<M::Distance>> {
+    sorted_indices(&lists.lefts)
+        .into_iter()
+        .zip(sorted_indices(&lists.rights))
+        .map(|(left_index, right_index)| {
+            let (left, right) = (lists.lefts[left_index], lists.rights[right_index]);
+
+            Pairing {
+                left,
+                right,
+                left_line: lists.line_numbers[left_index],
+                right_line: lists.line_numbers[right_index],
+                distance: metric.distance(left, right),
+            }
+        })
+        .collect()
+}
+
+/// Sums the distances between the sorted `lefts` and `rights`, the distances of the [`pairings`].
+///
+/// Panics if the sum overflows its [`Summable::Total`] type.
+pub fn distances_total<M: Metric>(
+    lefts: &[i32],
+    rights: &[i32],
+    metric: &M,
+) -> <M::Distance as Summable>::Total {
+    let mut lefts = lefts.to_vec();
+    let mut rights = rights.to_vec();
+
+    lefts.sort();
+    rights.sort();
+
+    lefts
+        .into_iter()
+        .zip(rights)
+        .try_fold(<M::Distance as Summable>::ZERO, |total, (left, right)| {
+            metric.distance(left, right).checked_add_to(total)
+        })
+        .expect("distances total overflows")
+}
+
+/// Sums integer distances in `$total`, which holds every `$distance`.
+macro_rules! impl_summable {
+    ($($distance:ty => $total:ty),*) => {
+        $(
+            impl Summable for $distance {
+                type Total = $total;
+
+                const ZERO: Self::Total = 0;
+
+                fn checked_add_to(self, total: Self::Total) -> Option<Self::Total> {
+                    total.checked_add(self.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_summable!(u32 => u64, u64 => u64, i32 => i64, i64 => i64);
+
+impl Summable for f64 {
+    type Total = f64;
+
+    const ZERO: Self::Total = 0.0;
+
+    /// Overflowing is reaching an infinite sum.
+    fn checked_add_to(self, total: Self::Total) -> Option<Self::Total> {
+        Some(total + self).filter(|total| total.is_finite())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{numbered_lefts_and_rights, Columns};
+
+    const LEFTS: [i32; 3] = [3, 1, 4];
+    const RIGHTS: [i32; 3] = [2, 8, 1];
+
+    #[test]
+    fn test_pairings() {
+        let lists =
+            numbered_lefts_and_rights("3   2\n\n1   8\n4   1\n", Columns::default()).unwrap();
+        let expected = vec![
+            Pairing {
+                left: 1,
+                right: 1,
+                left_line: 3,
+                right_line: 4,
+                distance: 0,
+            },
+            Pairing {
+                left: 3,
+                right: 2,
+                left_line: 1,
+                right_line: 1,
+                distance: 1,
+            },
+            Pairing {
+                left: 4,
+                right: 8,
+                left_line: 4,
+                right_line: 3,
+                distance: 4,
+            },
+        ];
+        let actual = pairings(&lists, &Absolute);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_distances_total() {
+        assert_eq!(5, distances_total(&LEFTS, &RIGHTS, &Absolute));
+        assert_eq!(17, distances_total(&LEFTS, &RIGHTS, &Squared));
+        assert_eq!(
+            1.0 / 3.0 * 100.0 + 50.0,
+            distances_total(&LEFTS, &RIGHTS, &Relative)
+        );
+        assert_eq!(
+            2,
+            distances_total(&LEFTS, &RIGHTS, &|left: i32, right: i32| {
+                i32::from(left != right)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "distances total overflows")]
+    fn test_distances_total_overflow() {
+        let lefts = [i32::MIN, i32::MIN];
+        let rights = [i32::MAX, i32::MAX];
+
+        distances_total(&lefts, &rights, &Squared);
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_2024_01::{distances_sum, similarity_score, Day01};
+use aoc_common::{Part, Solution};
+
+const INPUT_CONTENTS: &str = "1   2
+5   3
+3   3
+";
+
+#[test]
+fn test_solution() {
+    let input = Day01::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!((vec![1, 5, 3], vec![2, 3, 3]), input);
+    assert_eq!(3, Day01::solve(&input, Part::One));
+    assert_eq!(6, Day01::solve(&input, Part::Two));
+}
+
+#[test]
+fn test_entry_points() {
+    assert_eq!(Ok(3), distances_sum(INPUT_CONTENTS));
+    assert_eq!(Ok(6), similarity_score(INPUT_CONTENTS));
+}
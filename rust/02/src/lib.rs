@@ -0,0 +1,294 @@
+//! Day 2: Red-Nosed Reports, <https://adventofcode.com/2024/day/2>.
+#![warn(missing_docs)]
+
+use aoc_common::{error::parse_integer, ParseError, ParseErrorKind, Solution};
+
+pub use policy::{Direction, SafetyPolicy, ShortReportError, ShortReports};
+#[cfg(feature = "parallel")]
+pub use stream::par_safe_reports_amount_from_reader;
+pub use stream::{safe_reports_amount_from_reader, ReadReportsError};
+pub use verdict::{UnsafeReason, Verdict};
+
+pub mod policy;
+pub mod stream;
+pub mod verdict;
+
+/// Parses one report of levels per line.
+pub struct Day02;
+
+/// Tells whether `levels` are all increasing or all decreasing, by steps of 1 to 3. Reports with
+/// fewer than two levels are safe.
+pub fn is_report_safe(levels: &[i32]) -> bool {
+    SafetyPolicy::default()
+        .is_safe(levels)
+        .expect("the default policy accepts short reports")
+}
+
+/// Tells whether `levels` are [safe](is_report_safe) once at most one of them is removed, in
+/// linear time without allocating.
+pub fn is_report_safe_with_dampener(levels: &[i32]) -> bool {
+    SafetyPolicy::with_problem_dampener()
+        .is_safe(levels)
+        .expect("the default policy accepts short reports")
+}
+
+fn reports(input_contents: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input_contents
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|slice| parse_integer::<i32>(input_contents, slice))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+/// Locates a short report error at the end of `line`, the `line_index`th line of the input.
+fn short_report_error(line: &str, line_index: usize, error: ShortReportError) -> ParseError {
+    let kind = ParseErrorKind::TooFewValues {
+        min: 2,
+        actual: error.levels_amount,
+    };
+
+    ParseError::new(line_index + 1, line.chars().count() + 1, kind)
+}
+
+/// Returns the verdict of every report, locating short report errors in `input_contents`.
+fn verdicts(
+    input_contents: &str,
+    reports: &[Vec<i32>],
+    policy: &SafetyPolicy,
+) -> Result<Vec<Verdict>, ParseError> {
+    reports
+        .iter()
+        .zip(input_contents.lines())
+        .enumerate()
+        .map(|(i, (levels, line))| {
+            policy
+                .verdict(levels)
+                .map_err(|error| short_report_error(line, i, error))
+        })
+        .collect()
+}
+
+fn count_safe_reports(reports: &[Vec<i32>], policy: &SafetyPolicy) -> i64 {
+    reports
+        .iter()
+        .filter(|levels| {
+            policy
+                .is_safe(levels)
+                .expect("the puzzle policies accept short reports")
+        })
+        .count() as i64
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Error = ParseError;
+    type Answer = i64;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        reports(input_contents)
+    }
+
+    fn part_one(reports: &Self::Input) -> Self::Answer {
+        count_safe_reports(reports, &SafetyPolicy::default())
+    }
+
+    fn part_two(reports: &Self::Input) -> Self::Answer {
+        count_safe_reports(reports, &SafetyPolicy::with_problem_dampener())
+    }
+}
+
+/// Counts the reports that are safe according to `policy`. Blank lines are reports without
+/// levels, which fail under [`ShortReports::Error`].
+pub fn safe_reports_amount(input_contents: &str, policy: &SafetyPolicy) -> Result<i64, ParseError> {
+    let reports = Day02::parse(input_contents)?;
+    let verdicts = verdicts(input_contents, &reports, policy)?;
+
+    Ok(verdicts.iter().filter(|verdict| verdict.is_safe()).count() as i64)
+}
+
+/// Returns the [`Verdict`] of every report according to `policy`, in line order.
+pub fn report_verdicts(
+    input_contents: &str,
+    policy: &SafetyPolicy,
+) -> Result<Vec<Verdict>, ParseError> {
+    let reports = Day02::parse(input_contents)?;
+
+    verdicts(input_contents, &reports, policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const TEST_INPUT_CONTENTS: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn test_safe_reports_amount_with_problem_dampener_module_disabled() {
+        let expected = 2;
+        let actual = safe_reports_amount(TEST_INPUT_CONTENTS, &SafetyPolicy::default()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_safe_reports_amount_with_problem_dampener_module_enabled() {
+        let expected = 4;
+        let actual =
+            safe_reports_amount(TEST_INPUT_CONTENTS, &SafetyPolicy::with_problem_dampener())
+                .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_safe_reports_amount_with_malformed_input() {
+        let expected = "line 3, column 3: invalid integer `-`: invalid digit found in string";
+        let actual = safe_reports_amount("7 6 4\n1 2 7\n9 - 6\n", &SafetyPolicy::default())
+            .unwrap_err()
+            .to_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_safe_reports_amount_with_extreme_levels() {
+        let expected = 1;
+        let actual = safe_reports_amount(
+            "2147483647 2147483645\n-2147483648 2147483647\n",
+            &SafetyPolicy::default(),
+        )
+        .unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_report_verdicts() {
+        let expected = vec![
+            Verdict::Safe,
+            Verdict::Unsafe {
+                first_violation_index: 2,
+                reason: UnsafeReason::StepTooLarge,
+            },
+            Verdict::Unsafe {
+                first_violation_index: 3,
+                reason: UnsafeReason::StepTooLarge,
+            },
+            Verdict::SafeWithDampener { removed_index: 1 },
+            Verdict::SafeWithDampener { removed_index: 2 },
+            Verdict::Safe,
+        ];
+        let actual =
+            report_verdicts(TEST_INPUT_CONTENTS, &SafetyPolicy::with_problem_dampener()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_safe_reports_amount_with_short_reports() {
+        let input_contents = "1 2\n\n3\n";
+        let policy = |short_reports| SafetyPolicy {
+            short_reports,
+            ..SafetyPolicy::default()
+        };
+
+        assert_eq!(
+            Ok(3),
+            safe_reports_amount(input_contents, &policy(ShortReports::Safe))
+        );
+        assert_eq!(
+            Ok(1),
+            safe_reports_amount(input_contents, &policy(ShortReports::Unsafe))
+        );
+        assert_eq!(
+            "line 2, column 1: line has 0 values, expected at least 2",
+            safe_reports_amount(input_contents, &policy(ShortReports::Error))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 3, column 2: line has 1 values, expected at least 2",
+            safe_reports_amount("1 2\n3 4\n3\n", &policy(ShortReports::Error))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    /// The previous implementation, trying every report with one level removed.
+    fn is_report_safe_with_dampener_brute_force(levels: &[i32]) -> bool {
+        is_report_safe(levels)
+            || (0..levels.len()).any(|i| {
+                let mut dampened_levels = levels.to_vec();
+
+                dampened_levels.remove(i);
+                is_report_safe(&dampened_levels)
+            })
+    }
+
+    /// Reports walking by steps of -4 to 4, so that many of them are safe or nearly safe.
+    fn levels() -> impl Strategy<Value = Vec<i32>> {
+        (0..20_i32, prop::collection::vec(-4..=4_i32, 0..10)).prop_map(|(first_level, steps)| {
+            steps
+                .into_iter()
+                .scan(first_level, |level, step| {
+                    *level += step;
+                    Some(*level)
+                })
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_is_report_safe_with_dampener_matches_brute_force(levels in levels()) {
+            prop_assert_eq!(
+                is_report_safe_with_dampener_brute_force(&levels),
+                is_report_safe_with_dampener(&levels)
+            );
+        }
+
+        #[test]
+        fn test_verdict_matches_is_safe(levels in levels(), max_removals in 0..3_usize) {
+            let policy = SafetyPolicy {
+                max_removals,
+                ..SafetyPolicy::default()
+            };
+
+            prop_assert_eq!(
+                policy.is_safe(&levels),
+                policy.verdict(&levels).map(|verdict| verdict.is_safe())
+            );
+        }
+
+        #[test]
+        fn test_is_safe_with_two_removals_matches_brute_force(levels in levels()) {
+            let policy = SafetyPolicy {
+                max_removals: 2,
+                ..SafetyPolicy::default()
+            };
+            let expected = (0..levels.len()).any(|i| {
+                let mut dampened_levels = levels.clone();
+
+                dampened_levels.remove(i);
+                is_report_safe_with_dampener_brute_force(&dampened_levels)
+            }) || is_report_safe(&levels);
+
+            prop_assert_eq!(Ok(expected), policy.is_safe(&levels));
+        }
+
+        #[test]
+        fn test_is_report_safe_with_dampener_matches_brute_force_on_any_levels(
+            levels in prop::collection::vec(any::<i32>(), 0..6)
+        ) {
+            prop_assert_eq!(
+                is_report_safe_with_dampener_brute_force(&levels),
+                is_report_safe_with_dampener(&levels)
+            );
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! Configurable rules deciding whether a report is safe.
+
+use crate::verdict::{UnsafeReason, Verdict};
+use std::{cmp::Ordering, fmt, iter};
+
+/// The direction the levels of a safe report go in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Direction {
+    /// Every step increases.
+    Increasing,
+    /// Every step decreases.
+    Decreasing,
+    /// Every step increases, or every step decreases.
+    #[default]
+    Either,
+}
+
+/// How reports with fewer than two levels, which have no step to check, are handled.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ShortReports {
+    /// Short reports are safe, and the problem dampener may leave a single level.
+    #[default]
+    Safe,
+    /// Short reports are unsafe, and the problem dampener must leave at least two levels.
+    Unsafe,
+    /// Short reports are errors, and the problem dampener must leave at least two levels.
+    Error,
+}
+
+/// A report with fewer than two levels, under [`ShortReports::Error`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ShortReportError {
+    /// The number of levels of the report.
+    pub levels_amount: usize,
+}
+
+/// The rules a report must follow to be safe. The default is the puzzle's, without the problem
+/// dampener: strictly increasing or decreasing levels, by steps of 1 to 3.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SafetyPolicy {
+    /// The smallest difference between two neighbouring levels.
+    pub min_step: u32,
+    /// The largest difference between two neighbouring levels.
+    pub max_step: u32,
+    /// The direction the levels go in.
+    pub direction: Direction,
+    /// Whether neighbouring levels may be equal, whatever `min_step` and `direction` say.
+    pub allow_equal: bool,
+    /// How many levels the problem dampener may remove to make a report safe.
+    pub max_removals: usize,
+    /// How reports with fewer than two levels are handled.
+    pub short_reports: ShortReports,
+}
+
+/// A removed level index, chained to the previously removed ones on the stack so that trying
+/// removals never allocates.
+struct Removed<'a> {
+    index: usize,
+    previous: Option<&'a Removed<'a>>,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            allow_equal: false,
+            max_removals: 0,
+            short_reports: ShortReports::Safe,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// The puzzle's policy with the problem dampener, which may remove a single level.
+    pub fn with_problem_dampener() -> Self {
+        Self {
+            max_removals: 1,
+            ..Self::default()
+        }
+    }
+
+    fn orderings(&self) -> &'static [Ordering] {
+        match self.direction {
+            Direction::Increasing => &[Ordering::Less],
+            Direction::Decreasing => &[Ordering::Greater],
+            Direction::Either => &[Ordering::Less, Ordering::Greater],
+        }
+    }
+
+    fn is_safe_step(&self, current: i32, next: i32, ordering: Ordering) -> bool {
+        match current.cmp(&next) {
+            Ordering::Equal => self.allow_equal,
+            report_ordering => {
+                report_ordering == ordering
+                    && (self.min_step..=self.max_step).contains(&current.abs_diff(next))
+            }
+        }
+    }
+
+    /// Returns the indexes of the levels of the first unsafe step towards `ordering`, ignoring the
+    /// `removed` levels.
+    fn first_unsafe_step(
+        &self,
+        levels: &[i32],
+        removed: Option<&Removed>,
+        ordering: Ordering,
+    ) -> Option<(usize, usize)> {
+        let is_removed = |i| {
+            iter::successors(removed, |removed| removed.previous).any(|removed| removed.index == i)
+        };
+        let mut kept_levels = levels
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(i, _)| !is_removed(i));
+        let (mut prev_i, mut prev_level) = kept_levels.next()?;
+
+        for (i, level) in kept_levels {
+            if !self.is_safe_step(prev_level, level, ordering) {
+                return Some((prev_i, i));
+            }
+
+            (prev_i, prev_level) = (i, level);
+        }
+
+        None
+    }
+
+    /// Returns `None` when the report is unsafe despite removals, or else the smallest removed
+    /// index, if any.
+    ///
+    /// Any set of removals making the report safe must remove one of the two levels of its first
+    /// unsafe step, as they would stay neighbours otherwise, so trying both is enough.
+    fn safe_removal(
+        &self,
+        levels: &[i32],
+        removed: Option<&Removed>,
+        removals_left: usize,
+        ordering: Ordering,
+    ) -> Option<Option<usize>> {
+        let Some((prev_i, i)) = self.first_unsafe_step(levels, removed, ordering) else {
+            let kept_amount = levels.len() - (self.max_removals - removals_left);
+
+            if kept_amount < 2 && self.short_reports != ShortReports::Safe {
+                return None;
+            }
+
+            return Some(
+                iter::successors(removed, |removed| removed.previous)
+                    .map(|r| r.index)
+                    .min(),
+            );
+        };
+
+        if removals_left == 0 {
+            return None;
+        }
+
+        [prev_i, i].into_iter().find_map(|index| {
+            let removed = Removed {
+                index,
+                previous: removed,
+            };
+
+            self.safe_removal(levels, Some(&removed), removals_left - 1, ordering)
+        })
+    }
+
+    /// Returns the ordering of the first step that is not between equal levels, the one an
+    /// unsafe report is diagnosed against when both directions are allowed.
+    fn report_ordering(&self, levels: &[i32]) -> Ordering {
+        match self.direction {
+            Direction::Increasing => Ordering::Less,
+            Direction::Decreasing => Ordering::Greater,
+            Direction::Either => levels
+                .windows(2)
+                .map(|pair| pair[0].cmp(&pair[1]))
+                .find(|&ordering| ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Less),
+        }
+    }
+
+    fn unsafe_reason(&self, current: i32, next: i32, ordering: Ordering) -> UnsafeReason {
+        let step = current.abs_diff(next);
+
+        if current == next {
+            UnsafeReason::NoChange
+        } else if current.cmp(&next) != ordering {
+            UnsafeReason::DirectionFlip
+        } else if step > self.max_step {
+            UnsafeReason::StepTooLarge
+        } else {
+            UnsafeReason::StepTooSmall
+        }
+    }
+
+    /// Returns the verdict of a report with fewer than two levels, or `None` for longer ones.
+    fn short_report_verdict(&self, levels: &[i32]) -> Option<Result<Verdict, ShortReportError>> {
+        if levels.len() >= 2 {
+            return None;
+        }
+
+        Some(match self.short_reports {
+            ShortReports::Safe => Ok(Verdict::Safe),
+            ShortReports::Unsafe => Ok(Verdict::Unsafe {
+                first_violation_index: levels.len(),
+                reason: UnsafeReason::TooFewLevels,
+            }),
+            ShortReports::Error => Err(ShortReportError {
+                levels_amount: levels.len(),
+            }),
+        })
+    }
+
+    /// Tells whether `levels` follow this policy once at most `max_removals` of them are removed.
+    /// Fails only for reports with fewer than two levels under [`ShortReports::Error`].
+    ///
+    /// Runs in `O(2^max_removals * n)` without allocating, so in linear time for the puzzle.
+    pub fn is_safe(&self, levels: &[i32]) -> Result<bool, ShortReportError> {
+        if let Some(verdict) = self.short_report_verdict(levels) {
+            return verdict.map(|verdict| verdict.is_safe());
+        }
+
+        Ok(self.orderings().iter().any(|&ordering| {
+            self.safe_removal(levels, None, self.max_removals, ordering)
+                .is_some()
+        }))
+    }
+
+    /// Tells whether `levels` follow this policy like [`Self::is_safe`], with the removed level
+    /// or, for unsafe reports, the first rule they break.
+    pub fn verdict(&self, levels: &[i32]) -> Result<Verdict, ShortReportError> {
+        if let Some(verdict) = self.short_report_verdict(levels) {
+            return verdict;
+        }
+
+        let removal = self
+            .orderings()
+            .iter()
+            .filter_map(|&ordering| self.safe_removal(levels, None, self.max_removals, ordering))
+            .min();
+
+        Ok(match removal {
+            Some(None) => Verdict::Safe,
+            Some(Some(removed_index)) => Verdict::SafeWithDampener { removed_index },
+            None => {
+                let ordering = self.report_ordering(levels);
+                let (prev_i, i) = self
+                    .first_unsafe_step(levels, None, ordering)
+                    .expect("unsafe report has an unsafe step");
+
+                Verdict::Unsafe {
+                    first_violation_index: i,
+                    reason: self.unsafe_reason(levels[prev_i], levels[i], ordering),
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for ShortReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "report has {} levels, expected at least 2",
+            self.levels_amount
+        )
+    }
+}
+
+impl std::error::Error for ShortReportError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_safe_with_custom_policy() {
+        let policy = SafetyPolicy {
+            min_step: 2,
+            max_step: 5,
+            direction: Direction::Increasing,
+            allow_equal: true,
+            max_removals: 2,
+            short_reports: ShortReports::Safe,
+        };
+
+        assert!(policy.is_safe(&[1, 3, 3, 8]).unwrap());
+        assert!(policy.is_safe(&[1, 2, 4, 9]).unwrap());
+        assert!(!SafetyPolicy {
+            max_removals: 0,
+            ..policy
+        }
+        .is_safe(&[1, 2, 4, 9])
+        .unwrap());
+        assert!(policy.is_safe(&[1, 2, 9, 4, 6]).unwrap());
+        assert!(!policy.is_safe(&[9, 7, 5, 3]).unwrap());
+        assert!(!policy.is_safe(&[1, 2, 9, 4, 5, 7]).unwrap());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let policy = SafetyPolicy::with_problem_dampener();
+        let unsafe_verdict = |first_violation_index, reason| Verdict::Unsafe {
+            first_violation_index,
+            reason,
+        };
+
+        assert_eq!(Verdict::Safe, policy.verdict(&[7, 6, 4, 2, 1]).unwrap());
+        assert_eq!(
+            Verdict::SafeWithDampener { removed_index: 1 },
+            policy.verdict(&[1, 3, 2, 4, 5]).unwrap()
+        );
+        assert_eq!(
+            Verdict::SafeWithDampener { removed_index: 0 },
+            policy.verdict(&[5, 1, 2, 3]).unwrap()
+        );
+        assert_eq!(
+            unsafe_verdict(2, UnsafeReason::StepTooLarge),
+            policy.verdict(&[1, 2, 7, 8, 9]).unwrap()
+        );
+        assert_eq!(
+            unsafe_verdict(3, UnsafeReason::NoChange),
+            policy.verdict(&[8, 6, 4, 4, 4, 1]).unwrap()
+        );
+        assert_eq!(
+            unsafe_verdict(3, UnsafeReason::DirectionFlip),
+            policy.verdict(&[1, 2, 3, 2, 1]).unwrap()
+        );
+        assert_eq!(
+            unsafe_verdict(1, UnsafeReason::StepTooSmall),
+            SafetyPolicy {
+                min_step: 2,
+                ..SafetyPolicy::default()
+            }
+            .verdict(&[1, 2, 4])
+            .unwrap()
+        );
+        assert_eq!(
+            unsafe_verdict(1, UnsafeReason::DirectionFlip),
+            SafetyPolicy {
+                direction: Direction::Increasing,
+                ..SafetyPolicy::default()
+            }
+            .verdict(&[3, 2, 1])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_short_reports() {
+        let policy = |short_reports| SafetyPolicy {
+            short_reports,
+            ..SafetyPolicy::with_problem_dampener()
+        };
+        let too_few_levels = |first_violation_index| {
+            Ok(Verdict::Unsafe {
+                first_violation_index,
+                reason: UnsafeReason::TooFewLevels,
+            })
+        };
+
+        for levels in [&[][..], &[4]] {
+            assert_eq!(
+                Ok(Verdict::Safe),
+                policy(ShortReports::Safe).verdict(levels)
+            );
+            assert_eq!(
+                too_few_levels(levels.len()),
+                policy(ShortReports::Unsafe).verdict(levels)
+            );
+            assert_eq!(
+                Err(ShortReportError {
+                    levels_amount: levels.len()
+                }),
+                policy(ShortReports::Error).verdict(levels)
+            );
+        }
+
+        assert_eq!(
+            Ok(Verdict::SafeWithDampener { removed_index: 0 }),
+            policy(ShortReports::Safe).verdict(&[1, 9])
+        );
+        assert_eq!(
+            Ok(Verdict::Unsafe {
+                first_violation_index: 1,
+                reason: UnsafeReason::StepTooLarge
+            }),
+            policy(ShortReports::Error).verdict(&[1, 9])
+        );
+        assert_eq!(Ok(true), policy(ShortReports::Unsafe).is_safe(&[1, 2]));
+        assert_eq!(
+            "report has 1 levels, expected at least 2",
+            policy(ShortReports::Error)
+                .is_safe(&[4])
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
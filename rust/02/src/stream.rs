@@ -0,0 +1,268 @@
+//! Reports read line by line from any [`BufRead`], for logs too large to hold in memory.
+
+use crate::{short_report_error, SafetyPolicy};
+use aoc_common::{error::parse_integer, ParseError};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+/// The number of lines [`par_safe_reports_amount_from_reader`] holds and evaluates at once.
+#[cfg(any(feature = "parallel", test))]
+const BATCH_LINES: usize = 16_384;
+
+/// Failure to read reports from a reader.
+#[derive(Debug)]
+pub enum ReadReportsError {
+    /// The reader failed, or its contents are not UTF-8.
+    Io(io::Error),
+    /// A report is malformed, or too short for the policy.
+    Parse(ParseError),
+}
+
+/// Strips the line ending `str::lines` strips too.
+fn trim_line_ending(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Parses `line`, the `line_index`th line of the input, into `levels` and tells whether the
+/// report is safe.
+fn is_line_safe(
+    line: &str,
+    line_index: usize,
+    levels: &mut Vec<i32>,
+    policy: &SafetyPolicy,
+) -> Result<bool, ParseError> {
+    levels.clear();
+
+    for token in line.split_whitespace() {
+        let level = parse_integer(line, token).map_err(|error| ParseError {
+            line: line_index + 1,
+            ..error
+        })?;
+
+        levels.push(level);
+    }
+
+    policy
+        .is_safe(levels)
+        .map_err(|error| short_report_error(line, line_index, error))
+}
+
+/// Counts the reports of `reader` that are safe according to `policy`, like
+/// [`crate::safe_reports_amount`], holding a single line in memory at a time.
+pub fn safe_reports_amount_from_reader<R: BufRead>(
+    mut reader: R,
+    policy: &SafetyPolicy,
+) -> Result<i64, ReadReportsError> {
+    let mut line = String::new();
+    let mut levels = Vec::new();
+    let mut line_index = 0;
+    let mut safe_reports_amount = 0;
+
+    while reader.read_line(&mut line)? > 0 {
+        let is_safe = is_line_safe(trim_line_ending(&line), line_index, &mut levels, policy)?;
+
+        safe_reports_amount += i64::from(is_safe);
+        line_index += 1;
+        line.clear();
+    }
+
+    Ok(safe_reports_amount)
+}
+
+/// Counts the reports of `reader` like [`safe_reports_amount_from_reader`], evaluating batches of
+/// lines in parallel. Fails with the error of the first failing line, like the sequential version.
+#[cfg(feature = "parallel")]
+pub fn par_safe_reports_amount_from_reader<R: BufRead>(
+    reader: R,
+    policy: &SafetyPolicy,
+) -> Result<i64, ReadReportsError> {
+    let mut lines = reader.lines();
+    let mut first_line_index = 0;
+    let mut safe_reports_amount = 0;
+
+    loop {
+        let mut batch = Vec::with_capacity(BATCH_LINES);
+        let mut read_error = None;
+
+        for line in lines.by_ref().take(BATCH_LINES) {
+            match line {
+                Ok(line) => batch.push(line),
+                Err(error) => {
+                    read_error = Some(error);
+                    break;
+                }
+            }
+        }
+
+        if batch.is_empty() && read_error.is_none() {
+            return Ok(safe_reports_amount);
+        }
+
+        let are_safe = batch
+            .par_iter()
+            .enumerate()
+            .map_init(Vec::new, |levels, (i, line)| {
+                is_line_safe(line, first_line_index + i, levels, policy)
+            })
+            .collect::<Vec<_>>();
+
+        // The lines read before a read error come first, so their errors win.
+        for is_safe in are_safe {
+            safe_reports_amount += i64::from(is_safe?);
+        }
+
+        if let Some(error) = read_error {
+            return Err(error.into());
+        }
+
+        first_line_index += batch.len();
+    }
+}
+
+impl From<io::Error> for ReadReportsError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ParseError> for ReadReportsError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl fmt::Display for ReadReportsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "cannot read reports: {error}"),
+            Self::Parse(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadReportsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{safe_reports_amount, ShortReports};
+
+    /// More lines than a batch, with every kind of report and line ending.
+    fn large_input_contents() -> String {
+        let reports = [
+            "7 6 4 2 1",
+            "1 2 7 8 9",
+            "1 3 2 4 5\r",
+            "8 6 4 4 1",
+            "",
+            "5",
+        ];
+
+        (0..3 * BATCH_LINES)
+            .map(|i| format!("{}\n", reports[i % reports.len()]))
+            .collect()
+    }
+
+    #[test]
+    fn test_safe_reports_amount_from_reader() {
+        let input_contents = large_input_contents();
+
+        for policy in [
+            SafetyPolicy::default(),
+            SafetyPolicy::with_problem_dampener(),
+        ] {
+            let expected = safe_reports_amount(&input_contents, &policy).unwrap();
+
+            assert_eq!(
+                expected,
+                safe_reports_amount_from_reader(input_contents.as_bytes(), &policy).unwrap()
+            );
+            #[cfg(feature = "parallel")]
+            assert_eq!(
+                expected,
+                par_safe_reports_amount_from_reader(input_contents.as_bytes(), &policy).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_safe_reports_amount_from_reader_errors() {
+        let input_contents = large_input_contents();
+        let policy = SafetyPolicy {
+            short_reports: ShortReports::Error,
+            ..SafetyPolicy::default()
+        };
+
+        let expected = safe_reports_amount(&input_contents, &policy)
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(
+            "line 5, column 1: line has 0 values, expected at least 2",
+            expected
+        );
+        assert_eq!(
+            expected,
+            safe_reports_amount_from_reader(input_contents.as_bytes(), &policy)
+                .unwrap_err()
+                .to_string()
+        );
+        #[cfg(feature = "parallel")]
+        assert_eq!(
+            expected,
+            par_safe_reports_amount_from_reader(input_contents.as_bytes(), &policy)
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(matches!(
+            safe_reports_amount_from_reader(&[0xff, b'\n'][..], &policy),
+            Err(ReadReportsError::Io(_))
+        ));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_safe_reports_amount_from_reader_errors() {
+        let mut input_contents = large_input_contents();
+        input_contents.push_str("1 2 x\n");
+        assert_eq!(
+            format!(
+                "line {}, column 5: invalid integer `x`: invalid digit found in string",
+                3 * BATCH_LINES + 1
+            ),
+            par_safe_reports_amount_from_reader(
+                input_contents.as_bytes(),
+                &SafetyPolicy::default()
+            )
+            .unwrap_err()
+            .to_string()
+        );
+
+        let input_contents = b"1 2 3\n1 2 x\n\xff\n";
+        let expected = "line 2, column 5: invalid integer `x`: invalid digit found in string";
+        for actual in [
+            safe_reports_amount_from_reader(&input_contents[..], &SafetyPolicy::default()),
+            par_safe_reports_amount_from_reader(&input_contents[..], &SafetyPolicy::default()),
+        ] {
+            assert_eq!(expected, actual.unwrap_err().to_string());
+        }
+
+        assert!(matches!(
+            par_safe_reports_amount_from_reader(&b"1 2 3\n\xff\n"[..], &SafetyPolicy::default()),
+            Err(ReadReportsError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! Why a report is safe or unsafe.
+
+use std::fmt;
+
+/// The rule broken by the first unsafe step of a report.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnsafeReason {
+    /// The levels differ by more than the largest step.
+    StepTooLarge,
+    /// The levels differ by less than the smallest step, without being equal.
+    StepTooSmall,
+    /// The levels are equal.
+    NoChange,
+    /// The levels go in the other direction than the previous ones, or than the policy's.
+    DirectionFlip,
+    /// The report has fewer than two levels, and the policy treats such reports as unsafe.
+    TooFewLevels,
+}
+
+/// Whether a report is safe, and if not, why.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    /// The report is safe as it is.
+    Safe,
+    /// The report is safe once the problem dampener removes a level.
+    SafeWithDampener {
+        /// The index of the removed level, the smallest one when several are removed.
+        removed_index: usize,
+    },
+    /// The report is unsafe, even with the problem dampener.
+    Unsafe {
+        /// The index of the level ending the first unsafe step of the report as it is, or the
+        /// number of levels of a report too short to have a step.
+        first_violation_index: usize,
+        /// The rule broken by that step.
+        reason: UnsafeReason,
+    },
+}
+
+impl Verdict {
+    /// Tells whether the report is safe, with or without the problem dampener.
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Self::Unsafe { .. })
+    }
+}
+
+impl fmt::Display for UnsafeReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StepTooLarge => write!(f, "step too large"),
+            Self::StepTooSmall => write!(f, "step too small"),
+            Self::NoChange => write!(f, "no change"),
+            Self::DirectionFlip => write!(f, "direction flip"),
+            Self::TooFewLevels => write!(f, "too few levels"),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    /// Levels are numbered from 1, like lines.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Safe => write!(f, "safe"),
+            Self::SafeWithDampener { removed_index } => {
+                write!(f, "safe without level {}", removed_index + 1)
+            }
+            Self::Unsafe {
+                reason: UnsafeReason::TooFewLevels,
+                ..
+            } => write!(f, "unsafe: {}", UnsafeReason::TooFewLevels),
+            Self::Unsafe {
+                first_violation_index,
+                reason,
+            } => write!(f, "unsafe at level {}: {reason}", first_violation_index + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict_display() {
+        let verdicts = [
+            Verdict::Safe,
+            Verdict::SafeWithDampener { removed_index: 0 },
+            Verdict::Unsafe {
+                first_violation_index: 2,
+                reason: UnsafeReason::StepTooLarge,
+            },
+            Verdict::Unsafe {
+                first_violation_index: 1,
+                reason: UnsafeReason::TooFewLevels,
+            },
+        ];
+
+        let expected = vec![
+            "safe",
+            "safe without level 1",
+            "unsafe at level 3: step too large",
+            "unsafe: too few levels",
+        ];
+        let actual = verdicts.map(|verdict| verdict.to_string());
+        assert_eq!(expected, actual);
+    }
+}
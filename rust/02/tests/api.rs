@@ -0,0 +1,53 @@
+use aoc_2024_02::{
+    is_report_safe, safe_reports_amount, Day02, Direction, SafetyPolicy, ShortReports,
+};
+use aoc_common::{Part, Solution};
+
+#[test]
+fn test_is_report_safe() {
+    assert!(is_report_safe(&[1, 2, 5, 6]));
+    assert!(is_report_safe(&[9, 8]));
+    assert!(is_report_safe(&[4]));
+    assert!(is_report_safe(&[]));
+    assert!(!is_report_safe(&[1, 5]));
+    assert!(!is_report_safe(&[1, 2, 2]));
+    assert!(!is_report_safe(&[1, 3, 2]));
+}
+
+#[test]
+fn test_solution() {
+    let input_contents = "1 2 5 6\n1 3 2 4\n1 7 8 9 15\n";
+    let input = Day02::parse(input_contents).unwrap();
+
+    assert_eq!(1, Day02::solve(&input, Part::One));
+    assert_eq!(2, Day02::solve(&input, Part::Two));
+    assert_eq!(
+        Ok(2),
+        safe_reports_amount(input_contents, &SafetyPolicy::with_problem_dampener())
+    );
+}
+
+#[test]
+fn test_custom_policy() {
+    let input_contents = "1 1 6 11\n1 6 11 10\n11 6 1\n";
+    let policy = SafetyPolicy {
+        min_step: 5,
+        max_step: 5,
+        direction: Direction::Increasing,
+        allow_equal: true,
+        max_removals: 0,
+        short_reports: ShortReports::Unsafe,
+    };
+
+    assert_eq!(Ok(1), safe_reports_amount(input_contents, &policy));
+    assert_eq!(
+        Ok(2),
+        safe_reports_amount(
+            input_contents,
+            &SafetyPolicy {
+                max_removals: 1,
+                ..policy
+            }
+        )
+    );
+}
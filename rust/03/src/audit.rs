@@ -0,0 +1,160 @@
+//! Where every `mul` instruction is, and whether it counts towards the enabled sum.
+
+use crate::{
+    lexer::{tokenize, TokenKind},
+    Grammar, Instruction,
+};
+use std::fmt;
+
+/// A place in the corrupted memory.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+    /// The byte offset from the start of the memory.
+    pub offset: usize,
+    /// The 1-based line.
+    pub line: usize,
+    /// The 1-based column, in characters.
+    pub column: usize,
+}
+
+/// A `mul` instruction, with its result and what disabled it, if anything.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MulAudit {
+    /// Where the instruction starts.
+    pub location: Location,
+    /// The operands of the instruction.
+    pub operands: (i32, i32),
+    /// The result of the instruction.
+    pub product: i32,
+    /// Where the latest `don't` instruction before this one starts, unless a `do` instruction
+    /// followed it. The grammar decides whether those need their parentheses.
+    /// `None` when the instruction is enabled.
+    pub disabled_by: Option<Location>,
+}
+
+/// Tracks the line and column of increasing byte offsets.
+struct Cursor<'a> {
+    input_contents: &'a str,
+    location: Location,
+}
+
+impl Cursor<'_> {
+    fn advance(&mut self, offset: usize) -> Location {
+        for char in self.input_contents[self.location.offset..offset].chars() {
+            if char == '\n' {
+                self.location.line += 1;
+                self.location.column = 1;
+            } else {
+                self.location.column += 1;
+            }
+        }
+
+        self.location.offset = offset;
+        self.location
+    }
+}
+
+/// Lists every `mul` instruction of `input_contents` in order, with `do()` and `don't()`
+/// recognised by `grammar`. Part one sums all of them and part two only the enabled ones.
+pub fn audit(input_contents: &str, grammar: Grammar) -> Vec<MulAudit> {
+    let mut cursor = Cursor {
+        input_contents,
+        location: Location {
+            offset: 0,
+            line: 1,
+            column: 1,
+        },
+    };
+    let mut disabled_by = None;
+    let mut audits = Vec::new();
+
+    for token in tokenize(input_contents, grammar) {
+        let TokenKind::Instruction(instruction) = token.kind else {
+            continue;
+        };
+        let location = cursor.advance(token.span.start);
+
+        match instruction {
+            Instruction::Mul(first, second) => audits.push(MulAudit {
+                location,
+                operands: (first, second),
+                product: first * second,
+                disabled_by,
+            }),
+            Instruction::Do => disabled_by = None,
+            Instruction::Dont => disabled_by = Some(location),
+        }
+    }
+
+    audits
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}, byte {}",
+            self.line, self.column, self.offset
+        )
+    }
+}
+
+impl fmt::Display for MulAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, second) = self.operands;
+
+        write!(
+            f,
+            "{}: mul({first},{second}) = {}, ",
+            self.location, self.product
+        )?;
+
+        match self.disabled_by {
+            Some(location) => write!(f, "disabled by don't at {location}"),
+            None => write!(f, "enabled"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit() {
+        let input_contents = "mul(2,3)don't()\nxé_mul(4,5)\ndo()mul(6,7)";
+        let location = |offset, line, column| Location {
+            offset,
+            line,
+            column,
+        };
+        let expected = vec![
+            MulAudit {
+                location: location(0, 1, 1),
+                operands: (2, 3),
+                product: 6,
+                disabled_by: None,
+            },
+            MulAudit {
+                location: location(20, 2, 4),
+                operands: (4, 5),
+                product: 20,
+                disabled_by: Some(location(8, 1, 9)),
+            },
+            MulAudit {
+                location: location(33, 3, 5),
+                operands: (6, 7),
+                product: 42,
+                disabled_by: None,
+            },
+        ];
+        let actual = audit(input_contents, Grammar::Strict);
+
+        assert_eq!(expected, actual);
+        assert_eq!(
+            "line 2, column 4, byte 20: mul(4,5) = 20, disabled by don't at line 1, column 9, \
+             byte 8",
+            actual[1].to_string()
+        );
+    }
+}
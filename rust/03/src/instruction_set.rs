@@ -0,0 +1,507 @@
+//! An engine running any set of instructions, each bringing its own syntax and effect.
+
+use crate::{interpreter::State, Instruction};
+use std::{
+    io::{self, Read},
+    ops::Range,
+};
+
+/// The number of bytes [`InstructionSet::run_from_reader`] reads at once.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// How `do()` and `don't()` are recognised.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Grammar {
+    /// Only `do()` and `don't()` are instructions.
+    #[default]
+    Strict,
+    /// `do` and `don't` are instructions even without their parentheses, so `undo` or `dont`
+    /// enable the following `mul` instructions.
+    Lenient,
+}
+
+/// An instruction with its own syntax and effect on the interpreter state `S`.
+pub trait InstructionKind<S> {
+    /// Matches the instruction at the start of `bytes`, returning its operands and length.
+    /// Only the first [`Self::max_length`] bytes may decide the match.
+    fn lex(&self, bytes: &[u8]) -> Option<(Vec<i32>, usize)>;
+
+    /// Returns the length of the longest instruction of this kind.
+    fn max_length(&self) -> usize;
+
+    /// Applies the instruction to `state`, with the operands returned by [`Self::lex`].
+    fn execute(&self, operands: &[i32], state: &mut S);
+}
+
+/// Instructions tried in order at every byte of the corrupted memory, so earlier ones take
+/// precedence when several match at the same offset.
+pub struct InstructionSet<S> {
+    kinds: Vec<Box<dyn InstructionKind<S>>>,
+}
+
+/// An instruction found in the corrupted memory by an [`InstructionSet`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    /// The index of the instruction kind in its set.
+    pub kind_index: usize,
+    /// The operands of the instruction.
+    pub operands: Vec<i32>,
+    /// Where the instruction is in the memory.
+    pub span: Range<usize>,
+}
+
+/// `mul(X,Y)`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Mul;
+
+/// `do()`, or `do` under the [lenient](Grammar::Lenient) grammar.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Do(pub Grammar);
+
+/// `don't()`, or `don't` under the [lenient](Grammar::Lenient) grammar.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dont(pub Grammar);
+
+/// Parses 1 to 3 digits at the start of `bytes`, returning their value and length.
+fn operand(bytes: &[u8]) -> Option<(i32, usize)> {
+    let length = bytes
+        .iter()
+        .take(3)
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    let value = bytes[..length]
+        .iter()
+        .fold(0, |value, byte| value * 10 + i32::from(byte - b'0'));
+
+    (length > 0).then_some((value, length))
+}
+
+/// Returns the length of the longest `name(X,Y,…)` [`call`] with `arity` operands.
+pub fn call_max_length(name: &str, arity: usize) -> usize {
+    name.len() + "()".len() + arity * 3 + arity.saturating_sub(1)
+}
+
+/// Matches `name(X,Y,…)` with `arity` operands of 1 to 3 digits at the start of `bytes`,
+/// returning the operands and the length of the call.
+pub fn call(bytes: &[u8], name: &str, arity: usize) -> Option<(Vec<i32>, usize)> {
+    if !bytes.starts_with(name.as_bytes()) || bytes.get(name.len()) != Some(&b'(') {
+        return None;
+    }
+
+    let mut length = name.len() + 1;
+    let mut operands = Vec::with_capacity(arity);
+
+    for i in 0..arity {
+        if i > 0 {
+            if bytes.get(length) != Some(&b',') {
+                return None;
+            }
+
+            length += 1;
+        }
+
+        let (operand, operand_length) = operand(&bytes[length..])?;
+
+        operands.push(operand);
+        length += operand_length;
+    }
+
+    (bytes.get(length) == Some(&b')')).then_some((operands, length + 1))
+}
+
+/// Matches the `do` or `don't` keyword `name` at the start of `bytes` according to `grammar`,
+/// returning its length.
+fn keyword(bytes: &[u8], name: &str, grammar: Grammar) -> Option<usize> {
+    match grammar {
+        Grammar::Strict => call(bytes, name, 0).map(|(_, length)| length),
+        Grammar::Lenient => bytes.starts_with(name.as_bytes()).then_some(name.len()),
+    }
+}
+
+impl<S> InstructionSet<S> {
+    /// Creates a set without instructions.
+    pub fn new() -> Self {
+        Self { kinds: Vec::new() }
+    }
+
+    /// Adds `kind` to the set, after the previous ones.
+    pub fn with(mut self, kind: impl InstructionKind<S> + 'static) -> Self {
+        self.kinds.push(Box::new(kind));
+        self
+    }
+
+    fn match_at(&self, bytes: &[u8], offset: usize) -> Option<Match> {
+        self.kinds
+            .iter()
+            .enumerate()
+            .find_map(|(kind_index, kind)| {
+                let (operands, length) = kind.lex(&bytes[offset..])?;
+
+                Some(Match {
+                    kind_index,
+                    operands,
+                    span: offset..offset + length,
+                })
+            })
+    }
+
+    /// Returns the first instruction of `input_contents` starting at or after `offset`.
+    pub fn find(&self, input_contents: &str, offset: usize) -> Option<Match> {
+        let bytes = input_contents.as_bytes();
+
+        (offset..bytes.len()).find_map(|i| self.match_at(bytes, i))
+    }
+
+    /// Returns the instructions of `input_contents` in order, skipping everything else.
+    pub fn matches<'a>(&'a self, input_contents: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut offset = 0;
+
+        std::iter::from_fn(move || {
+            let found = self.find(input_contents, offset)?;
+
+            offset = found.span.end;
+            Some(found)
+        })
+    }
+
+    fn lookahead(&self) -> usize {
+        self.kinds
+            .iter()
+            .map(|kind| kind.max_length())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Applies `found`, an instruction of this set, to `state`.
+    pub fn execute(&self, found: &Match, state: &mut S) {
+        self.kinds[found.kind_index].execute(&found.operands, state);
+    }
+
+    /// Runs the instructions of `input_contents` on `state`, returning the final state.
+    pub fn run(&self, input_contents: &str, mut state: S) -> S {
+        for found in self.matches(input_contents) {
+            self.execute(&found, &mut state);
+        }
+
+        state
+    }
+
+    /// Runs the instructions read from `reader` on `state` like [`Self::run`], reading
+    /// [`CHUNK_SIZE`] bytes at once and holding at most a chunk and an instruction in memory.
+    pub fn run_from_reader<R: Read>(&self, reader: R, state: S) -> io::Result<S> {
+        self.run_from_chunks(reader, CHUNK_SIZE, state)
+    }
+
+    fn run_from_chunks<R: Read>(
+        &self,
+        mut reader: R,
+        chunk_size: usize,
+        mut state: S,
+    ) -> io::Result<S> {
+        let lookahead = self.lookahead();
+        let mut buffer = Vec::with_capacity(chunk_size + lookahead);
+        let mut chunk = vec![0; chunk_size];
+        let mut offset = 0;
+
+        loop {
+            let read_length = match reader.read(&mut chunk) {
+                Ok(read_length) => read_length,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            let is_end = read_length == 0;
+
+            buffer.extend_from_slice(&chunk[..read_length]);
+
+            // An instruction is only decided once all the bytes it may span are read.
+            while offset < buffer.len() && (is_end || buffer.len() - offset >= lookahead) {
+                match self.match_at(&buffer, offset) {
+                    Some(found) => {
+                        self.execute(&found, &mut state);
+                        offset = found.span.end;
+                    }
+                    None => offset += 1,
+                }
+            }
+
+            if is_end {
+                return Ok(state);
+            }
+
+            buffer.drain(..offset);
+            offset = 0;
+        }
+    }
+}
+
+impl<S> Default for InstructionSet<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: State> InstructionKind<S> for Mul {
+    fn lex(&self, bytes: &[u8]) -> Option<(Vec<i32>, usize)> {
+        call(bytes, "mul", 2)
+    }
+
+    fn max_length(&self) -> usize {
+        call_max_length("mul", 2)
+    }
+
+    fn execute(&self, operands: &[i32], state: &mut S) {
+        state.execute(&Instruction::Mul(operands[0], operands[1]));
+    }
+}
+
+impl<S: State> InstructionKind<S> for Do {
+    fn lex(&self, bytes: &[u8]) -> Option<(Vec<i32>, usize)> {
+        Some((Vec::new(), keyword(bytes, "do", self.0)?))
+    }
+
+    fn max_length(&self) -> usize {
+        call_max_length("do", 0)
+    }
+
+    fn execute(&self, _operands: &[i32], state: &mut S) {
+        state.execute(&Instruction::Do);
+    }
+}
+
+impl<S: State> InstructionKind<S> for Dont {
+    fn lex(&self, bytes: &[u8]) -> Option<(Vec<i32>, usize)> {
+        Some((Vec::new(), keyword(bytes, "don't", self.0)?))
+    }
+
+    fn max_length(&self) -> usize {
+        call_max_length("don't", 0)
+    }
+
+    fn execute(&self, _operands: &[i32], state: &mut S) {
+        state.execute(&Instruction::Dont);
+    }
+}
+
+/// The part one instruction set: `mul` alone.
+pub fn multiplications<S: State>() -> InstructionSet<S> {
+    InstructionSet::new().with(Mul)
+}
+
+/// The part two instruction set: `mul`, and `don't` and `do` recognised by `grammar`.
+pub fn enabled_multiplications<S: State>(grammar: Grammar) -> InstructionSet<S> {
+    InstructionSet::new()
+        .with(Mul)
+        .with(Dont(grammar))
+        .with(Do(grammar))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::{EnabledMultiplicationsSum, MultiplicationsSum};
+
+    /// An accumulator, enabled or disabled like the `mul` instructions.
+    #[derive(Default)]
+    struct Accumulator {
+        enabled_sum: EnabledMultiplicationsSum,
+        value: i32,
+    }
+
+    /// `add(X,Y)` or `sub(X,Y)`, adding `X + Y` or `X - Y` to the accumulator when enabled.
+    struct Arithmetic {
+        name: &'static str,
+        operation: fn(i32, i32) -> i32,
+    }
+
+    /// `reset()`, zeroing the accumulator.
+    struct Reset;
+
+    impl State for Accumulator {
+        fn execute(&mut self, instruction: &Instruction) {
+            self.enabled_sum.execute(instruction);
+        }
+    }
+
+    impl InstructionKind<Accumulator> for Arithmetic {
+        fn lex(&self, bytes: &[u8]) -> Option<(Vec<i32>, usize)> {
+            call(bytes, self.name, 2)
+        }
+
+        fn max_length(&self) -> usize {
+            call_max_length(self.name, 2)
+        }
+
+        fn execute(&self, operands: &[i32], state: &mut Accumulator) {
+            if state.enabled_sum.enabled {
+                state.value += (self.operation)(operands[0], operands[1]);
+            }
+        }
+    }
+
+    impl InstructionKind<Accumulator> for Reset {
+        fn lex(&self, bytes: &[u8]) -> Option<(Vec<i32>, usize)> {
+            call(bytes, "reset", 0)
+        }
+
+        fn max_length(&self) -> usize {
+            call_max_length("reset", 0)
+        }
+
+        fn execute(&self, _operands: &[i32], state: &mut Accumulator) {
+            state.value = 0;
+        }
+    }
+
+    #[test]
+    fn test_call() {
+        assert_eq!(Some((vec![12, 345], 11)), call(b"mul(12,345)x", "mul", 2));
+        assert_eq!(Some((vec![], 7)), call(b"reset()", "reset", 0));
+        assert_eq!(None, call(b"mul(1234,5)", "mul", 2));
+        assert_eq!(None, call(b"mul(1,2,3)", "mul", 2));
+        assert_eq!(None, call(b"mul(1,2", "mul", 2));
+    }
+
+    #[test]
+    fn test_preconfigured_instruction_sets() {
+        let input_contents = "mul(2,3)don't()mul(4,5)undo_mul(1,1)do()mul(6,7)";
+
+        assert_eq!(
+            69,
+            multiplications()
+                .run(input_contents, MultiplicationsSum::default())
+                .sum
+        );
+        assert_eq!(
+            48,
+            enabled_multiplications(Grammar::Strict)
+                .run(input_contents, EnabledMultiplicationsSum::default())
+                .sum
+        );
+        assert_eq!(
+            49,
+            enabled_multiplications(Grammar::Lenient)
+                .run(input_contents, EnabledMultiplicationsSum::default())
+                .sum
+        );
+    }
+
+    #[test]
+    fn test_custom_instruction_set() {
+        let instruction_set = InstructionSet::new()
+            .with(Arithmetic {
+                name: "add",
+                operation: |first, second| first + second,
+            })
+            .with(Arithmetic {
+                name: "sub",
+                operation: |first, second| first - second,
+            })
+            .with(Reset)
+            .with(Dont(Grammar::Strict))
+            .with(Do(Grammar::Strict));
+        let input_contents = "add(1,2)reset()add(10,20)%sub(3,5)don't()add(100,1)do()sub(7,1)";
+
+        assert_eq!(
+            vec![0, 2, 0, 1, 3, 0, 4, 1],
+            instruction_set
+                .matches(input_contents)
+                .map(|found| found.kind_index)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            34,
+            instruction_set
+                .run(input_contents, Accumulator::default())
+                .value
+        );
+    }
+
+    #[test]
+    fn test_run_from_chunks_beyond_i32() {
+        let input_contents = "mul(999,999)".repeat(3_000);
+        let expected = 3_000 * 999 * 999;
+
+        assert!(expected > i64::from(i32::MAX));
+
+        for chunk_size in [1, 7, CHUNK_SIZE] {
+            let actual = (
+                multiplications()
+                    .run_from_chunks(
+                        input_contents.as_bytes(),
+                        chunk_size,
+                        MultiplicationsSum::default(),
+                    )
+                    .unwrap()
+                    .sum,
+                enabled_multiplications(Grammar::Strict)
+                    .run_from_chunks(
+                        input_contents.as_bytes(),
+                        chunk_size,
+                        EnabledMultiplicationsSum::default(),
+                    )
+                    .unwrap()
+                    .sum,
+            );
+
+            assert_eq!((expected, expected), actual, "chunks of {chunk_size}");
+        }
+    }
+
+    /// Reads from `bytes`, failing with [`io::ErrorKind::Interrupted`] before the first read.
+    struct InterruptedOnce<'a> {
+        bytes: &'a [u8],
+        interrupted: bool,
+    }
+
+    impl Read for InterruptedOnce<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            if !self.interrupted {
+                self.interrupted = true;
+
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+
+            self.bytes.read(buffer)
+        }
+    }
+
+    #[test]
+    fn test_run_from_reader_retries_interrupted_reads() {
+        let reader = InterruptedOnce {
+            bytes: b"mul(2,3)don't()mul(4,5)",
+            interrupted: false,
+        };
+        let expected = 26;
+        let actual = multiplications()
+            .run_from_reader(reader, MultiplicationsSum::default())
+            .unwrap()
+            .sum;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_run_from_chunks() {
+        let input_contents =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\
+            dondon't()mul(123,456)mumul(1,1)do()don'tmul(999,999)";
+
+        for grammar in [Grammar::Strict, Grammar::Lenient] {
+            let instruction_set = enabled_multiplications(grammar);
+            let expected =
+                instruction_set.run(input_contents, EnabledMultiplicationsSum::default());
+
+            for chunk_size in 1..=input_contents.len() {
+                let actual = instruction_set
+                    .run_from_chunks(
+                        input_contents.as_bytes(),
+                        chunk_size,
+                        EnabledMultiplicationsSum::default(),
+                    )
+                    .unwrap();
+
+                assert_eq!(expected, actual, "{grammar:?}, chunks of {chunk_size}");
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Runs instructions on a pluggable [`State`].
+
+use crate::Instruction;
+
+/// The state of the machine running a program, updated by each of its instructions in order.
+pub trait State {
+    /// Applies `instruction` to the state.
+    fn execute(&mut self, instruction: &Instruction);
+}
+
+/// Sums the results of every `mul` instruction.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MultiplicationsSum {
+    /// The sum so far.
+    pub sum: i64,
+}
+
+/// Sums the results of the `mul` instructions enabled by the latest `do` or `don't`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EnabledMultiplicationsSum {
+    /// Whether the following `mul` instructions count, as they do at the start.
+    pub enabled: bool,
+    /// The sum so far.
+    pub sum: i64,
+}
+
+/// Adds `first * second` to `sum`.
+///
+/// Panics if the sum overflows `i64`.
+fn add_product(sum: i64, first: i32, second: i32) -> i64 {
+    sum.checked_add(i64::from(first) * i64::from(second))
+        .expect("multiplications sum overflows i64")
+}
+
+/// Runs `instructions` on `state`, returning the final state.
+pub fn run<'a, S: State>(
+    instructions: impl IntoIterator<Item = &'a Instruction>,
+    mut state: S,
+) -> S {
+    for instruction in instructions {
+        state.execute(instruction);
+    }
+
+    state
+}
+
+/// Keeps the latest instruction, telling which one a built-in instruction kind runs.
+impl State for Option<Instruction> {
+    fn execute(&mut self, instruction: &Instruction) {
+        *self = Some(*instruction);
+    }
+}
+
+impl State for MultiplicationsSum {
+    fn execute(&mut self, instruction: &Instruction) {
+        if let Instruction::Mul(first, second) = instruction {
+            self.sum = add_product(self.sum, *first, *second);
+        }
+    }
+}
+
+impl Default for EnabledMultiplicationsSum {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sum: 0,
+        }
+    }
+}
+
+impl State for EnabledMultiplicationsSum {
+    fn execute(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::Mul(first, second) if self.enabled => {
+                self.sum = add_product(self.sum, *first, *second);
+            }
+            Instruction::Mul(..) => (),
+            Instruction::Do => self.enabled = true,
+            Instruction::Dont => self.enabled = false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the `mul` instructions skipped while disabled.
+    #[derive(Default)]
+    struct SkippedMultiplications {
+        enabled_sum: EnabledMultiplicationsSum,
+        skipped: usize,
+    }
+
+    impl State for SkippedMultiplications {
+        fn execute(&mut self, instruction: &Instruction) {
+            if matches!(instruction, Instruction::Mul(..)) && !self.enabled_sum.enabled {
+                self.skipped += 1;
+            }
+
+            self.enabled_sum.execute(instruction);
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let instructions = [
+            Instruction::Mul(2, 3),
+            Instruction::Dont,
+            Instruction::Mul(4, 5),
+            Instruction::Mul(1, 1),
+            Instruction::Do,
+            Instruction::Mul(6, 7),
+        ];
+
+        assert_eq!(69, run(&instructions, MultiplicationsSum::default()).sum);
+
+        let state = run(&instructions, SkippedMultiplications::default());
+
+        assert_eq!((48, 2), (state.enabled_sum.sum, state.skipped));
+    }
+}
@@ -0,0 +1,110 @@
+//! Splits the corrupted memory into instructions and the noise between them, as matched by the
+//! built-in [`InstructionSet`].
+
+use crate::{
+    instruction_set::{enabled_multiplications, Grammar, InstructionSet},
+    Instruction,
+};
+use std::ops::Range;
+
+/// What a [`Token`] is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind {
+    /// A well-formed instruction.
+    Instruction(Instruction),
+    /// Anything between instructions.
+    Noise,
+}
+
+/// A piece of the corrupted memory, located by its byte offsets.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Token {
+    /// What the piece is.
+    pub kind: TokenKind,
+    /// Where the piece is in the memory.
+    pub span: Range<usize>,
+}
+
+/// An iterator over the [`Token`]s of the corrupted memory, covering all of it in order.
+pub struct Tokens<'a> {
+    input_contents: &'a str,
+    instruction_set: InstructionSet<Option<Instruction>>,
+    offset: usize,
+}
+
+/// Splits `input_contents` into [`Token`]s according to `grammar`.
+pub fn tokenize(input_contents: &str, grammar: Grammar) -> Tokens<'_> {
+    Tokens {
+        input_contents,
+        instruction_set: enabled_multiplications(grammar),
+        offset: 0,
+    }
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.offset;
+
+        if start == self.input_contents.len() {
+            return None;
+        }
+
+        let kind = match self.instruction_set.find(self.input_contents, start) {
+            Some(found) if found.span.start == start => {
+                let mut instruction = None;
+
+                self.instruction_set.execute(&found, &mut instruction);
+                self.offset = found.span.end;
+                TokenKind::Instruction(instruction.expect("built-in instructions run themselves"))
+            }
+            found => {
+                // Instructions are ASCII, so the noise ends on a char boundary.
+                self.offset = found.map_or(self.input_contents.len(), |found| found.span.start);
+                TokenKind::Noise
+            }
+        };
+
+        Some(Token {
+            kind,
+            span: start..self.offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let input_contents = "xmul(2,4)%mul(1234,5)don't()_mul(5,5é";
+        let expected = vec![
+            (TokenKind::Noise, "x"),
+            (TokenKind::Instruction(Instruction::Mul(2, 4)), "mul(2,4)"),
+            (TokenKind::Noise, "%mul(1234,5)"),
+            (TokenKind::Instruction(Instruction::Dont), "don't"),
+            (TokenKind::Noise, "()_mul(5,5é"),
+        ];
+        let actual = tokenize(input_contents, Grammar::Lenient)
+            .map(|token| (token.kind, &input_contents[token.span]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_tokenize_strictly() {
+        let input_contents = "undo()don't_do(";
+        let instructions = |grammar| {
+            tokenize(input_contents, grammar)
+                .filter(|token| token.kind != TokenKind::Noise)
+                .map(|token| &input_contents[token.span])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["do()"], instructions(Grammar::Strict));
+        assert_eq!(vec!["do", "don't", "do"], instructions(Grammar::Lenient));
+    }
+}
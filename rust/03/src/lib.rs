@@ -0,0 +1,154 @@
+//! Day 3: Mull It Over, <https://adventofcode.com/2024/day/3>.
+#![warn(missing_docs)]
+
+use aoc_common::Solution;
+use instruction_set::{enabled_multiplications, multiplications};
+use interpreter::{run, EnabledMultiplicationsSum, MultiplicationsSum};
+use lexer::{tokenize, TokenKind};
+use std::{
+    convert::Infallible,
+    io::{self, Read},
+};
+
+pub use instruction_set::Grammar;
+
+pub mod audit;
+pub mod instruction_set;
+pub mod interpreter;
+pub mod lexer;
+
+/// Parses the instructions found in the corrupted memory, skipping everything else.
+pub struct Day03;
+
+/// An instruction found in the corrupted memory.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    /// `mul(X,Y)`, with `X` and `Y` of 1 to 3 digits.
+    Mul(i32, i32),
+    /// `do()`, which enables the following `mul` instructions.
+    Do,
+    /// `don't()`, which disables the following `mul` instructions.
+    Dont,
+}
+
+fn instructions(input_contents: &str, grammar: Grammar) -> Vec<Instruction> {
+    tokenize(input_contents, grammar)
+        .filter_map(|token| match token.kind {
+            TokenKind::Instruction(instruction) => Some(instruction),
+            TokenKind::Noise => None,
+        })
+        .collect()
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+    type Error = Infallible;
+    type Answer = i64;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        Ok(instructions(input_contents, Grammar::Strict))
+    }
+
+    fn part_one(instructions: &Self::Input) -> Self::Answer {
+        run(instructions, MultiplicationsSum::default()).sum
+    }
+
+    fn part_two(instructions: &Self::Input) -> Self::Answer {
+        run(instructions, EnabledMultiplicationsSum::default()).sum
+    }
+}
+
+/// Sums the results of every `mul` instruction.
+///
+/// Panics if the sum overflows `i64`.
+pub fn multiplications_sum(input_contents: &str) -> i64 {
+    Day03::part_one(&instructions(input_contents, Grammar::Strict))
+}
+
+/// Sums the results of the `mul` instructions enabled by the latest `do()` or `don't()`, as
+/// recognised by `grammar`.
+///
+/// Panics if the sum overflows `i64`.
+pub fn enabled_multiplications_sum(input_contents: &str, grammar: Grammar) -> i64 {
+    Day03::part_two(&instructions(input_contents, grammar))
+}
+
+/// Sums the results of every `mul` instruction read from `reader`, chunk by chunk, like
+/// [`multiplications_sum`].
+pub fn multiplications_sum_from_reader<R: Read>(reader: R) -> io::Result<i64> {
+    let state = multiplications().run_from_reader(reader, MultiplicationsSum::default())?;
+
+    Ok(state.sum)
+}
+
+/// Sums the results of the enabled `mul` instructions read from `reader`, chunk by chunk, like
+/// [`enabled_multiplications_sum`].
+pub fn enabled_multiplications_sum_from_reader<R: Read>(
+    reader: R,
+    grammar: Grammar,
+) -> io::Result<i64> {
+    let state = enabled_multiplications(grammar)
+        .run_from_reader(reader, EnabledMultiplicationsSum::default())?;
+
+    Ok(state.sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const TEST_INPUT_CONTENTS_2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_multiplications_sum() {
+        let expected = 161;
+        let actual = multiplications_sum(TEST_INPUT_CONTENTS);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_enabled_multiplications_sum() {
+        let expected = 48;
+        let actual = enabled_multiplications_sum(TEST_INPUT_CONTENTS_2, Grammar::Strict);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_sums_from_reader() {
+        let expected = (161, 48);
+        let actual = (
+            multiplications_sum_from_reader(TEST_INPUT_CONTENTS.as_bytes()).unwrap(),
+            enabled_multiplications_sum_from_reader(
+                TEST_INPUT_CONTENTS_2.as_bytes(),
+                Grammar::Strict,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_enabled_multiplications_sum_with_diverging_grammars() {
+        for (input_contents, strict_sum, lenient_sum) in [
+            ("don't()mul(1,2)undo_mul(2,3)", 0, 6),
+            ("don't()mul(1,2)dont mul(2,3)", 0, 6),
+            ("mul(2,3)don'tmul(4,5)", 26, 6),
+            ("mul(2,3)don't()mul(4,5)do()mul(1,1)", 7, 7),
+        ] {
+            assert_eq!(
+                (strict_sum, lenient_sum),
+                (
+                    enabled_multiplications_sum(input_contents, Grammar::Strict),
+                    enabled_multiplications_sum(input_contents, Grammar::Lenient)
+                ),
+                "{input_contents}"
+            );
+        }
+    }
+}
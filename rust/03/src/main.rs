@@ -1,79 +1,41 @@
-use regex::Regex;
-
-const INPUT_CONTENTS: &str = include_str!("../../../inputs/03/input");
-
-fn multiplications_sum(input_contents: &str) -> i32 {
-    let regex = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
-
-    regex
-        .captures_iter(input_contents)
-        .map(|captures| {
-            let (_, [first, second]) = captures.extract();
-
-            first.parse::<i32>().unwrap() * second.parse::<i32>().unwrap()
-        })
-        .sum::<i32>()
-}
-
-fn enabled_multiplications_sum(input_contents: &str) -> i32 {
-    let regex = Regex::new(
-        r"(mul\((?<first>[0-9]{1,3}),(?<second>[0-9]{1,3})\))|(?<disabler>don't)|(?<enabler>do)",
-    )
-    .unwrap();
-
-    let mut enabled = true;
-
-    regex
-        .captures_iter(input_contents)
-        .filter_map(|captures| {
-            enabled = match (captures.name("disabler"), captures.name("enabler")) {
-                (Some(_), _) => false,
-                (_, Some(_)) => true,
-                _ => enabled,
-            };
-
-            if enabled {
-                let first = captures.name("first")?.as_str().parse::<i32>().unwrap();
-                let second = captures.name("second")?.as_str().parse::<i32>().unwrap();
-
-                return Some(first * second);
-            }
-
-            None
-        })
-        .sum()
-}
+use aoc_2024_03::{audit::audit, enabled_multiplications_sum, multiplications_sum, Grammar};
+use aoc_common::input;
+use std::{env, process::ExitCode};
+
+/// Argument listing every `mul` instruction instead of printing the sums.
+const AUDIT_ARG: &str = "--audit";
+/// Argument recognising `do` and `don't` without their parentheses, see [`Grammar::Lenient`].
+const LENIENT_ARG: &str = "--lenient";
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let audit_requested = input::take_flag(&mut args, AUDIT_ARG);
+    let grammar = if input::take_flag(&mut args, LENIENT_ARG) {
+        Grammar::Lenient
+    } else {
+        Grammar::Strict
+    };
+    let input_contents = match input::read(3, args.first().map(String::as_str)) {
+        Ok(input_contents) => input_contents,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if audit_requested {
+        for mul_audit in audit(&input_contents, grammar) {
+            println!("{mul_audit}");
+        }
+
+        return ExitCode::SUCCESS;
+    }
 
-fn main() {
-    let result = multiplications_sum(INPUT_CONTENTS);
+    let result = multiplications_sum(&input_contents);
     println!("Multiplications sum: {result}");
 
-    let result = enabled_multiplications_sum(INPUT_CONTENTS);
+    let result = enabled_multiplications_sum(&input_contents, grammar);
     println!("Enabled multiplications sum: {result}");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_INPUT_CONTENTS: &str =
-        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-    const TEST_INPUT_CONTENTS_2: &str =
-        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
-    #[test]
-    fn test_multiplications_sum() {
-        let expected = 161;
-        let actual = multiplications_sum(TEST_INPUT_CONTENTS);
-
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn test_enabled_multiplications_sum() {
-        let expected = 48;
-        let actual = enabled_multiplications_sum(TEST_INPUT_CONTENTS_2);
-
-        assert_eq!(expected, actual);
-    }
+    ExitCode::SUCCESS
 }
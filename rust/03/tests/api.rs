@@ -0,0 +1,29 @@
+use aoc_2024_03::{enabled_multiplications_sum, multiplications_sum, Day03, Grammar, Instruction};
+use aoc_common::{Part, Solution};
+
+const INPUT_CONTENTS: &str = "mul(2,3)don't()mul(4,5)mul(1000,1)do()mul[1,1]mul(6,7)";
+
+#[test]
+fn test_solution() {
+    let input = Day03::parse(INPUT_CONTENTS).unwrap();
+    let expected = vec![
+        Instruction::Mul(2, 3),
+        Instruction::Dont,
+        Instruction::Mul(4, 5),
+        Instruction::Do,
+        Instruction::Mul(6, 7),
+    ];
+
+    assert_eq!(expected, input);
+    assert_eq!(68, Day03::solve(&input, Part::One));
+    assert_eq!(48, Day03::solve(&input, Part::Two));
+}
+
+#[test]
+fn test_entry_points() {
+    assert_eq!(68, multiplications_sum(INPUT_CONTENTS));
+    assert_eq!(
+        48,
+        enabled_multiplications_sum(INPUT_CONTENTS, Grammar::Strict)
+    );
+}
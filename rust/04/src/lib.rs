@@ -0,0 +1,125 @@
+//! Day 4: Ceres Search, <https://adventofcode.com/2024/day/4>.
+#![warn(missing_docs)]
+
+use aoc_common::{
+    grid::{Coord, Direction, Grid},
+    ParseError, Solution,
+};
+
+/// Parses the word search into a grid of letters.
+pub struct Day04;
+
+fn find_xmas_matches(input: &str) -> usize {
+    input.matches("XMAS").count()
+}
+
+fn find_samx_matches(input: &str) -> usize {
+    input.matches("SAMX").count()
+}
+
+fn is_cross(grid: &Grid<char>, coord: Coord) -> bool {
+    let corner = |direction| grid.neighbor(coord, direction).map(|corner| grid[corner]);
+
+    matches!(
+        (
+            corner(Direction::UpLeft),
+            corner(Direction::UpRight),
+            grid[coord],
+            corner(Direction::DownLeft),
+            corner(Direction::DownRight),
+        ),
+        (Some('M'), Some('M'), 'A', Some('S'), Some('S'))
+            | (Some('S'), Some('S'), 'A', Some('M'), Some('M'))
+            | (Some('M'), Some('S'), 'A', Some('M'), Some('S'))
+            | (Some('S'), Some('M'), 'A', Some('S'), Some('M'))
+    )
+}
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type Error = ParseError;
+    type Answer = i32;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        input_contents.parse()
+    }
+
+    fn part_one(grid: &Self::Input) -> Self::Answer {
+        let lines = grid
+            .all_rows()
+            .chain(grid.all_columns())
+            .chain(grid.diagonals())
+            .chain(grid.anti_diagonals())
+            .map(String::from_iter);
+
+        lines
+            .map(|line| find_xmas_matches(&line) + find_samx_matches(&line))
+            .sum::<usize>() as i32
+    }
+
+    fn part_two(grid: &Self::Input) -> Self::Answer {
+        grid.coords().filter(|coord| is_cross(grid, *coord)).count() as i32
+    }
+}
+
+/// Counts `XMAS` in every direction, backwards and overlapping included.
+pub fn words_amount(input_contents: &str) -> Result<i32, ParseError> {
+    Ok(Day04::part_one(&Day04::parse(input_contents)?))
+}
+
+/// Counts the two `MAS` crossing each other diagonally, in any direction.
+pub fn crosses_amount(input_contents: &str) -> Result<i32, ParseError> {
+    Ok(Day04::part_two(&Day04::parse(input_contents)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS_WORDS_AMOUNT: &str = "....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX
+";
+    const TEST_INPUT_CONTENTS_CROSSES_AMOUNT: &str = ".M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+";
+
+    #[test]
+    fn test_words_amount() {
+        let expected = 18;
+        let actual = words_amount(TEST_INPUT_CONTENTS_WORDS_AMOUNT).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_crosses_amount() {
+        let expected = 9;
+        let actual = crosses_amount(TEST_INPUT_CONTENTS_CROSSES_AMOUNT).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_words_amount_with_malformed_input() {
+        let expected = "line 2, column 3: row has 2 cells, expected 4 like the first row";
+        let actual = words_amount("XMAS\nSA\n").unwrap_err().to_string();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_2024_04::{crosses_amount, words_amount, Day04};
+use aoc_common::{Part, Solution};
+
+const INPUT_CONTENTS: &str = "MXMX
+XASA
+SXSM
+XMAS
+";
+
+#[test]
+fn test_solution() {
+    let input = Day04::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!((4, 4), (input.rows(), input.cols()));
+    assert_eq!(1, Day04::solve(&input, Part::One));
+    assert_eq!(1, Day04::solve(&input, Part::Two));
+}
+
+#[test]
+fn test_entry_points() {
+    assert_eq!(Ok(1), words_amount(INPUT_CONTENTS));
+    assert_eq!(Ok(1), crosses_amount(INPUT_CONTENTS));
+}
@@ -0,0 +1,202 @@
+//! Day 5: Print Queue, <https://adventofcode.com/2024/day/5>.
+#![warn(missing_docs)]
+
+use aoc_common::{error::parse_integer, ParseError, ParseErrorKind, Solution};
+use std::{cmp::Ordering, collections::HashMap};
+
+/// A page number.
+pub type Page = i32;
+/// The pages that must be printed after each page.
+pub type Rules = HashMap<Page, Vec<Page>>;
+
+/// Parses the page ordering rules and the updates, separated by a blank line.
+pub struct Day05;
+
+/// The parsed puzzle input.
+pub struct PrintQueue {
+    /// The page ordering rules, from `X|Y` lines.
+    pub rules: Rules,
+    /// The pages of each update, from comma separated lines.
+    pub updates: Vec<Vec<Page>>,
+}
+
+fn collect_rules(input_contents: &str, rules_input: &str) -> Result<Rules, ParseError> {
+    rules_input.lines().try_fold(Rules::new(), |mut acc, line| {
+        let (greater, less) = line.split_once("|").ok_or_else(|| {
+            ParseError::at(
+                input_contents,
+                line,
+                ParseErrorKind::MissingSeparator("`|`"),
+            )
+        })?;
+
+        acc.entry(parse_integer::<Page>(input_contents, greater)?)
+            .or_default()
+            .push(parse_integer::<Page>(input_contents, less)?);
+        Ok(acc)
+    })
+}
+
+fn split_updates_line(updates_line: &str) -> std::str::Split<'_, &str> {
+    updates_line.split(",")
+}
+
+fn collect_updates(
+    input_contents: &str,
+    updates_input: &str,
+) -> Result<Vec<Vec<Page>>, ParseError> {
+    updates_input
+        .lines()
+        .map(|line| {
+            split_updates_line(line)
+                .map(|page| parse_integer::<Page>(input_contents, page))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+fn is_updates_line_sorted(updates_line: &[Page], rules: &Rules) -> bool {
+    updates_line.is_sorted_by(|a, b| match rules.get(b) {
+        Some(b_rule_values) => b_rule_values.iter().all(|v| v != a),
+        _ => true,
+    })
+}
+
+fn sort_updates_line_values(a: &Page, b: &Page, rules: &Rules) -> Ordering {
+    match (rules.get(a), rules.get(b)) {
+        (Some(a_rule_values), _) if a_rule_values.contains(b) => Ordering::Greater,
+        (_, Some(b_rule_values)) if b_rule_values.contains(a) => Ordering::Less,
+        _ => Ordering::Equal,
+    }
+}
+
+fn middle_page(updates_line: &[Page]) -> Page {
+    updates_line[updates_line.len() / 2]
+}
+
+impl Solution for Day05 {
+    type Input = PrintQueue;
+    type Error = ParseError;
+    type Answer = i32;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        let (rules_input, updates_input) = input_contents.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(
+                input_contents,
+                ParseErrorKind::MissingSeparator("blank line"),
+            )
+        })?;
+
+        Ok(PrintQueue {
+            rules: collect_rules(input_contents, rules_input)?,
+            updates: collect_updates(input_contents, updates_input)?,
+        })
+    }
+
+    fn part_one(print_queue: &Self::Input) -> Self::Answer {
+        let PrintQueue { rules, updates } = print_queue;
+
+        updates
+            .iter()
+            .filter(|updates_line| is_updates_line_sorted(updates_line, rules))
+            .map(|updates_line| middle_page(updates_line))
+            .sum::<i32>()
+    }
+
+    fn part_two(print_queue: &Self::Input) -> Self::Answer {
+        let PrintQueue { rules, updates } = print_queue;
+
+        updates
+            .iter()
+            .filter(|updates_line| !is_updates_line_sorted(updates_line, rules))
+            .map(|updates_line| {
+                let mut updates_line_values = updates_line.clone();
+
+                updates_line_values.sort_by(|a, b| sort_updates_line_values(a, b, rules));
+
+                middle_page(&updates_line_values)
+            })
+            .sum::<i32>()
+    }
+}
+
+/// Sums the middle pages of the updates already in the right order.
+pub fn correctly_ordered_updates_middle_pages_sum(input_contents: &str) -> Result<i32, ParseError> {
+    Ok(Day05::part_one(&Day05::parse(input_contents)?))
+}
+
+/// Sums the middle pages of the updates in the wrong order, once put in the right order.
+pub fn reordered_wrongly_ordered_updates_middle_pages_sum(
+    input_contents: &str,
+) -> Result<i32, ParseError> {
+    Ok(Day05::part_two(&Day05::parse(input_contents)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_correctly_ordered_updates_middle_pages_sum() {
+        let expected = 143;
+        let actual = correctly_ordered_updates_middle_pages_sum(TEST_INPUT_CONTENTS).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_reordered_wrongly_ordered_updates_middle_pages_sum() {
+        let expected = 123;
+        let actual =
+            reordered_wrongly_ordered_updates_middle_pages_sum(TEST_INPUT_CONTENTS).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_correctly_ordered_updates_middle_pages_sum_with_malformed_input() {
+        let expected = "line 2, column 1: missing `|` separator";
+        let actual = correctly_ordered_updates_middle_pages_sum("47|53\n97-13\n\n75,47,61\n")
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(expected, actual);
+
+        let expected = "line 3, column 1: missing blank line separator";
+        let actual = correctly_ordered_updates_middle_pages_sum("47|53\n75,47,61\n")
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(expected, actual);
+    }
+}
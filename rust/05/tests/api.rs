@@ -0,0 +1,34 @@
+use aoc_2024_05::{
+    correctly_ordered_updates_middle_pages_sum, reordered_wrongly_ordered_updates_middle_pages_sum,
+    Day05,
+};
+use aoc_common::{Part, Solution};
+
+const INPUT_CONTENTS: &str = "1|2
+2|3
+
+1,2,3
+3,2,1
+";
+
+#[test]
+fn test_solution() {
+    let input = Day05::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!(Some(&vec![2]), input.rules.get(&1));
+    assert_eq!(vec![vec![1, 2, 3], vec![3, 2, 1]], input.updates);
+    assert_eq!(2, Day05::solve(&input, Part::One));
+    assert_eq!(2, Day05::solve(&input, Part::Two));
+}
+
+#[test]
+fn test_entry_points() {
+    assert_eq!(
+        Ok(2),
+        correctly_ordered_updates_middle_pages_sum(INPUT_CONTENTS)
+    );
+    assert_eq!(
+        Ok(2),
+        reordered_wrongly_ordered_updates_middle_pages_sum(INPUT_CONTENTS)
+    );
+}
@@ -0,0 +1,206 @@
+//! Day 6: Guard Gallivant, <https://adventofcode.com/2024/day/6>.
+#![warn(missing_docs)]
+
+use ahash::AHashSet;
+use aoc_common::{
+    grid::{Coord, Direction, Grid},
+    ParseError, ParseErrorKind, Solution,
+};
+use rayon::prelude::*;
+
+/// A map tile: `.` for free space, `#` for an obstruction and `^` for the guard.
+pub type Tile = char;
+/// The lab map.
+pub type Map = Grid<Tile>;
+/// A position on the map.
+pub type Position = Coord;
+/// The distinct positions the guard walks through.
+pub type VisitedPositions = AHashSet<Position>;
+
+const OBSTRUCTED_TILE: Tile = '#';
+const GUARD_TILE: Tile = '^';
+
+/// Parses the lab map along with the guard starting position and direction.
+pub struct Day06;
+
+fn starting_direction(tile: Tile) -> Option<Direction> {
+    match tile {
+        GUARD_TILE => Some(Direction::Up),
+        _ => None,
+    }
+}
+
+/// Parses the map, the guard starting position and its starting direction.
+pub fn get_map_and_starting_values(
+    input_contents: &str,
+) -> Result<(Map, Position, Direction), ParseError> {
+    Day06::parse(input_contents)
+}
+
+fn is_obstructed(tile: Tile) -> bool {
+    tile == OBSTRUCTED_TILE
+}
+
+fn maybe_next_values(
+    map: &Map,
+    curr_pos: Position,
+    curr_dir: Direction,
+) -> Option<(Position, Direction)> {
+    let next_pos = map.neighbor(curr_pos, curr_dir)?;
+
+    if is_obstructed(map[next_pos]) {
+        return maybe_next_values(map, curr_pos, curr_dir.turn_right());
+    }
+
+    Some((next_pos, curr_dir))
+}
+
+/// Walks the guard from its starting values until it leaves the map, turning right before each
+/// obstruction. The guard must not be stuck in a loop.
+pub fn visited_positions(
+    map: &Map,
+    mut curr_pos: Position,
+    mut curr_dir: Direction,
+) -> VisitedPositions {
+    let mut visited_positions = VisitedPositions::with_capacity(map.rows() * map.cols());
+
+    visited_positions.insert(curr_pos);
+
+    while let Some((next_pos, next_dir)) = maybe_next_values(map, curr_pos, curr_dir) {
+        (curr_pos, curr_dir) = (next_pos, next_dir);
+
+        visited_positions.insert(curr_pos);
+    }
+
+    visited_positions
+}
+
+/// Counts the distinct positions the guard walks through.
+pub fn visited_positions_amount(visited_positions: &VisitedPositions) -> i32 {
+    visited_positions.len() as i32
+}
+
+fn is_stuck_in_loop(map: Map, mut curr_pos: Position, mut curr_dir: Direction) -> bool {
+    let mut visited_positions_and_direction: AHashSet<(Position, Direction)> =
+        AHashSet::with_capacity(map.rows() * map.cols());
+
+    while let Some((next_pos, next_dir)) = maybe_next_values(&map, curr_pos, curr_dir) {
+        (curr_pos, curr_dir) = (next_pos, next_dir);
+
+        if !visited_positions_and_direction.insert((curr_pos, curr_dir)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Counts the positions where a single new obstruction would get the guard stuck in a loop. Only
+/// the `visited_positions` other than the starting one can change the guard path.
+pub fn stuck_in_loop_amount(
+    map: &Map,
+    visited_positions: &VisitedPositions,
+    curr_pos: Position,
+    curr_dir: Direction,
+) -> i32 {
+    let stuck_in_loop = visited_positions
+        .par_iter()
+        .filter(|visited_pos| match **visited_pos {
+            visited_pos if visited_pos == curr_pos => false,
+            visited_pos => {
+                let mut map = map.clone();
+
+                map[visited_pos] = OBSTRUCTED_TILE;
+                is_stuck_in_loop(map, curr_pos, curr_dir)
+            }
+        });
+
+    stuck_in_loop.count() as i32
+}
+
+impl Solution for Day06 {
+    type Input = (Map, Position, Direction);
+    type Error = ParseError;
+    type Answer = i32;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        let map = input_contents.parse::<Map>()?;
+        let (curr_pos, curr_dir) = map
+            .iter()
+            .find_map(|(position, &tile)| Some((position, starting_direction(tile)?)))
+            .ok_or_else(|| {
+                ParseError::at_end(input_contents, ParseErrorKind::MissingTile(GUARD_TILE))
+            })?;
+
+        Ok((map, curr_pos, curr_dir))
+    }
+
+    fn part_one((map, curr_pos, curr_dir): &Self::Input) -> Self::Answer {
+        visited_positions_amount(&visited_positions(map, *curr_pos, *curr_dir))
+    }
+
+    fn part_two((map, curr_pos, curr_dir): &Self::Input) -> Self::Answer {
+        let visited_positions = visited_positions(map, *curr_pos, *curr_dir);
+
+        stuck_in_loop_amount(map, &visited_positions, *curr_pos, *curr_dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::input;
+
+    const TEST_INPUT_CONTENTS: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_visited_positions_amount() {
+        let expected = 41;
+        let (map, curr_pos, curr_dir) = get_map_and_starting_values(TEST_INPUT_CONTENTS).unwrap();
+        let visited_positions = visited_positions(&map, curr_pos, curr_dir);
+        let actual = visited_positions_amount(&visited_positions);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_stuck_in_loop_amount() {
+        let expected = 6;
+        let (map, curr_pos, curr_dir) = get_map_and_starting_values(TEST_INPUT_CONTENTS).unwrap();
+        let visited_positions = visited_positions(&map, curr_pos, curr_dir);
+        let actual = stuck_in_loop_amount(&map, &visited_positions, curr_pos, curr_dir);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_get_map_and_starting_values_without_guard() {
+        let expected = "line 3, column 1: missing `^` tile";
+        let actual = get_map_and_starting_values("..#\n...\n")
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_stuck_in_loop_amount_with_real_input() {
+        let expected = 1719;
+        let input_contents = input::read(6, None).unwrap();
+        let (map, curr_pos, curr_dir) = get_map_and_starting_values(&input_contents).unwrap();
+        let visited_positions = visited_positions(&map, curr_pos, curr_dir);
+        let actual = stuck_in_loop_amount(&map, &visited_positions, curr_pos, curr_dir);
+
+        assert_eq!(expected, actual);
+    }
+}
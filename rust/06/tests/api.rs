@@ -0,0 +1,33 @@
+use aoc_2024_06::{
+    get_map_and_starting_values, stuck_in_loop_amount, visited_positions, visited_positions_amount,
+    Day06,
+};
+use aoc_common::{grid::Coord, grid::Direction, Part, Solution};
+
+const INPUT_CONTENTS: &str = ".#..
+...#
+.^..
+..#.
+";
+
+#[test]
+fn test_solution() {
+    let input = Day06::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!((Coord::new(2, 1), Direction::Up), (input.1, input.2));
+    assert_eq!(5, Day06::solve(&input, Part::One));
+    assert_eq!(1, Day06::solve(&input, Part::Two));
+}
+
+#[test]
+fn test_entry_points() {
+    let (map, curr_pos, curr_dir) = get_map_and_starting_values(INPUT_CONTENTS).unwrap();
+    let visited_positions = visited_positions(&map, curr_pos, curr_dir);
+
+    assert!(visited_positions.contains(&Coord::new(1, 2)));
+    assert_eq!(5, visited_positions_amount(&visited_positions));
+    assert_eq!(
+        1,
+        stuck_in_loop_amount(&map, &visited_positions, curr_pos, curr_dir)
+    );
+}
@@ -0,0 +1,201 @@
+//! Day 7: Bridge Repair, <https://adventofcode.com/2024/day/7>.
+#![warn(missing_docs)]
+
+use aoc_common::{error::parse_integer, ParseError, ParseErrorKind, Solution};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+type Operation = fn(i64, i64) -> i64;
+
+/// Parses one calibration equation per line.
+pub struct Day07;
+
+/// A calibration equation, `result: test_values...`, missing its operators.
+pub struct Equation {
+    /// The value the operators must produce.
+    pub result: i64,
+    /// The operands, evaluated left to right. There is always at least one.
+    pub test_values: Vec<i64>,
+}
+
+fn combinations<'a, T: 'a + Copy>(a: &'a [T], n: usize) -> Vec<Vec<T>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+
+    let mut result = Vec::new();
+
+    for v in a {
+        let mut sub_combinations = combinations(a, n - 1);
+        for combination in &mut sub_combinations {
+            combination.push(*v);
+        }
+        result.extend(sub_combinations);
+    }
+
+    result
+}
+
+fn valid_combinations_results(
+    combinations: &[Vec<Operation>],
+    test_values: &[i64],
+    result: i64,
+) -> Option<i64> {
+    combinations
+        .par_iter()
+        .any(|operations| any_valid_combination(operations, test_values, result))
+        .then_some(result)
+}
+
+fn any_valid_combination(operations: &[Operation], test_values: &[i64], result: i64) -> bool {
+    let mut operations_iter = operations.iter();
+    let test_result = test_values
+        .iter()
+        .copied()
+        .reduce(|acc, test_value| operations_iter.next().unwrap()(acc, test_value))
+        .unwrap();
+
+    test_result == result
+}
+
+fn equation(input_contents: &str, line: &str) -> Result<Equation, ParseError> {
+    let (result_string, test_values_string) = line.split_once(':').ok_or_else(|| {
+        ParseError::at(
+            input_contents,
+            line,
+            ParseErrorKind::MissingSeparator("`:`"),
+        )
+    })?;
+    let result = parse_integer(input_contents, result_string)?;
+    let test_values = test_values_string
+        .split_whitespace()
+        .map(|v| parse_integer(input_contents, v))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if test_values.is_empty() {
+        return Err(ParseError::at(
+            input_contents,
+            test_values_string,
+            ParseErrorKind::MissingValue("test values"),
+        ));
+    }
+
+    Ok(Equation {
+        result,
+        test_values,
+    })
+}
+
+fn is_valid_equation(
+    equation: &Equation,
+    operations: &[Operation],
+    combinations_cache: &mut HashMap<usize, Vec<Vec<Operation>>>,
+) -> Option<i64> {
+    let combinations_amount = equation.test_values.len() - 1;
+    let combinations = combinations_cache
+        .entry(combinations_amount)
+        .or_insert_with(|| combinations(operations, combinations_amount));
+
+    valid_combinations_results(combinations, &equation.test_values, equation.result)
+}
+
+fn total_calibration(equations: &[Equation], operations: &[Operation]) -> i64 {
+    let mut combinations_cache = HashMap::new();
+
+    equations
+        .iter()
+        .filter_map(|equation| is_valid_equation(equation, operations, &mut combinations_cache))
+        .sum::<i64>()
+}
+
+fn concatenate(a: i64, b: i64) -> i64 {
+    (a.to_string() + &b.to_string()).parse::<i64>().unwrap()
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Error = ParseError;
+    type Answer = i64;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        input_contents
+            .lines()
+            .map(|line| equation(input_contents, line))
+            .collect()
+    }
+
+    fn part_one(equations: &Self::Input) -> Self::Answer {
+        let operations = vec![<i64 as std::ops::Add>::add, <i64 as std::ops::Mul>::mul];
+
+        total_calibration(equations, &operations)
+    }
+
+    fn part_two(equations: &Self::Input) -> Self::Answer {
+        let operations = vec![
+            <i64 as std::ops::Add>::add,
+            <i64 as std::ops::Mul>::mul,
+            concatenate,
+        ];
+
+        total_calibration(equations, &operations)
+    }
+}
+
+/// Sums the results of the equations that `+` and `*` can make true.
+pub fn total_calibration_result(input_contents: &str) -> Result<i64, ParseError> {
+    Ok(Day07::part_one(&Day07::parse(input_contents)?))
+}
+
+/// Sums the results of the equations that `+`, `*` and digit concatenation can make true.
+pub fn total_calibration_with_concatenation_result(
+    input_contents: &str,
+) -> Result<i64, ParseError> {
+    Ok(Day07::part_two(&Day07::parse(input_contents)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_total_calibration_result() {
+        let expected = 3749_i64;
+        let actual = total_calibration_result(TEST_INPUT_CONTENTS).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_total_calibration_with_concatenations_result() {
+        let expected = 11387_i64;
+        let actual = total_calibration_with_concatenation_result(TEST_INPUT_CONTENTS).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_total_calibration_result_with_malformed_input() {
+        let expected = "line 2, column 1: missing `:` separator";
+        let actual = total_calibration_result("190: 10 19\n3267 81 40 27\n")
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(expected, actual);
+
+        let expected = "line 1, column 5: missing test values";
+        let actual = total_calibration_result("190:\n").unwrap_err().to_string();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_2024_07::{
+    total_calibration_result, total_calibration_with_concatenation_result, Day07, Equation,
+};
+use aoc_common::{Part, Solution};
+
+const INPUT_CONTENTS: &str = "10: 2 5
+25: 2 5
+30: 1 2 3
+";
+
+#[test]
+fn test_solution() {
+    let input = Day07::parse(INPUT_CONTENTS).unwrap();
+    let Equation {
+        result,
+        test_values,
+    } = &input[2];
+
+    assert_eq!((30, &vec![1, 2, 3]), (*result, test_values));
+    assert_eq!(10, Day07::solve(&input, Part::One));
+    assert_eq!(35, Day07::solve(&input, Part::Two));
+}
+
+#[test]
+fn test_entry_points() {
+    assert_eq!(Ok(10), total_calibration_result(INPUT_CONTENTS));
+    assert_eq!(
+        Ok(35),
+        total_calibration_with_concatenation_result(INPUT_CONTENTS)
+    );
+}
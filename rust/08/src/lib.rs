@@ -0,0 +1,156 @@
+//! Day 8: Resonant Collinearity, <https://adventofcode.com/2024/day/8>.
+#![warn(missing_docs)]
+
+use aoc_common::{
+    grid::{Coord, Grid},
+    ParseError, Solution,
+};
+use std::collections::HashSet;
+
+/// Parses the antennas map into a grid.
+pub struct Day08;
+
+fn antennas(grid: &Grid<char>) -> Vec<(Coord, char)> {
+    grid.iter()
+        .filter(|(_, char)| char.is_alphanumeric())
+        .map(|(coord, char)| (coord, *char))
+        .collect::<Vec<_>>()
+}
+
+fn generate_antinodes(antennas_pair: (Coord, Coord), grid: &Grid<char>) -> Vec<Coord> {
+    let (antenna1, antenna2) = antennas_pair;
+    let delta = (antenna2 - antenna1) * 2;
+    let antinodes = [grid.offset(antenna1, delta), grid.offset(antenna2, -delta)];
+
+    antinodes.into_iter().flatten().collect::<Vec<_>>()
+}
+
+fn generate_antinodes_along_all_the_line(
+    antennas_pair: (Coord, Coord),
+    grid: &Grid<char>,
+) -> Vec<Coord> {
+    let (antenna1, antenna2) = antennas_pair;
+    let in_bound_antinodes = (1..)
+        .map_while(|i| {
+            let delta = (antenna2 - antenna1) * i;
+            let antinodes = [grid.offset(antenna1, delta), grid.offset(antenna2, -delta)];
+            let in_bound_antinodes = antinodes.into_iter().flatten().collect::<Vec<_>>();
+
+            (!in_bound_antinodes.is_empty()).then_some(in_bound_antinodes)
+        })
+        .flatten();
+
+    in_bound_antinodes.collect::<Vec<_>>()
+}
+
+fn antennas_pair_to_antinodes(
+    antennas_pair: (Coord, Coord),
+    grid: &Grid<char>,
+    antinodes_along_all_the_line: bool,
+) -> Option<Vec<Coord>> {
+    let antinodes = match antinodes_along_all_the_line {
+        false => generate_antinodes(antennas_pair, grid),
+        true => generate_antinodes_along_all_the_line(antennas_pair, grid),
+    };
+
+    (!antinodes.is_empty()).then_some(antinodes)
+}
+
+fn antennas_antinodes(
+    antennas: &[(Coord, char)],
+    grid: &Grid<char>,
+    antinodes_along_all_the_line: bool,
+) -> HashSet<Coord> {
+    antennas
+        .iter()
+        .flat_map(move |antenna1| {
+            antennas
+                .iter()
+                .filter_map(move |antenna2| match (antenna1, antenna2) {
+                    ((coord1, c1), (coord2, c2)) if c1 == c2 && coord1 < coord2 => {
+                        antennas_pair_to_antinodes(
+                            (*coord1, *coord2),
+                            grid,
+                            antinodes_along_all_the_line,
+                        )
+                    }
+                    _ => None,
+                })
+        })
+        .flatten()
+        .collect()
+}
+
+/// Finds the distinct in bound antinodes of every pair of antennas of the same frequency. Without
+/// `antinodes_along_all_the_line`, only the two points twice as far from one antenna as from the
+/// other are antinodes.
+pub fn antinodes(grid: &Grid<char>, antinodes_along_all_the_line: bool) -> HashSet<Coord> {
+    let antennas = antennas(grid);
+
+    antennas_antinodes(&antennas, grid, antinodes_along_all_the_line)
+}
+
+fn antinodes_amount_in(grid: &Grid<char>, antinodes_along_all_the_line: bool) -> i32 {
+    antinodes(grid, antinodes_along_all_the_line).len() as i32
+}
+
+impl Solution for Day08 {
+    type Input = Grid<char>;
+    type Error = ParseError;
+    type Answer = i32;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        input_contents.parse()
+    }
+
+    fn part_one(grid: &Self::Input) -> Self::Answer {
+        antinodes_amount_in(grid, false)
+    }
+
+    fn part_two(grid: &Self::Input) -> Self::Answer {
+        antinodes_amount_in(grid, true)
+    }
+}
+
+/// Counts the [`antinodes`] of the parsed map.
+pub fn antinodes_amount(
+    input_contents: &str,
+    antinodes_along_all_the_line: bool,
+) -> Result<i32, ParseError> {
+    let grid = Day08::parse(input_contents)?;
+
+    Ok(antinodes_amount_in(&grid, antinodes_along_all_the_line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn test_in_bound_antinodes_amount_part_one() {
+        let expected = 14;
+        let actual = antinodes_amount(TEST_INPUT_CONTENTS, false).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_in_bound_antinodes_amount_part_two() {
+        let expected = 34;
+        let actual = antinodes_amount(TEST_INPUT_CONTENTS, true).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_2024_08::{antinodes, antinodes_amount, Day08};
+use aoc_common::{grid::Coord, Part, Solution};
+use std::collections::HashSet;
+
+const INPUT_CONTENTS: &str = ".....
+.a...
+..a..
+.....
+.....
+";
+
+#[test]
+fn test_antinodes() {
+    let grid = Day08::parse(INPUT_CONTENTS).unwrap();
+    let expected = HashSet::from([Coord::new(0, 0), Coord::new(3, 3)]);
+
+    assert_eq!(expected, antinodes(&grid, false));
+    assert_eq!(5, antinodes(&grid, true).len());
+}
+
+#[test]
+fn test_solution() {
+    let input = Day08::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!(2, Day08::solve(&input, Part::One));
+    assert_eq!(5, Day08::solve(&input, Part::Two));
+    assert_eq!(Ok(5), antinodes_amount(INPUT_CONTENTS, true));
+}
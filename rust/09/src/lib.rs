@@ -0,0 +1,260 @@
+//! Day 9: Disk Fragmenter, <https://adventofcode.com/2024/day/9>.
+#![warn(missing_docs)]
+
+use aoc_common::{ParseError, ParseErrorKind, Solution};
+
+/// Parses the disk map into the blocks it describes.
+pub struct Day09;
+
+/// A disk block.
+#[derive(Clone, Debug)]
+pub enum BlockEntry {
+    /// A block of the file with this id.
+    FileId(i64),
+    /// A free block.
+    FreeSpace,
+}
+
+#[derive(Debug)]
+struct File {
+    start_index: usize,
+    size: usize,
+}
+
+fn blocks_amount(
+    input_contents: &str,
+    disk_map: &str,
+    i: usize,
+    char: char,
+) -> Result<u32, ParseError> {
+    char.to_digit(10).ok_or_else(|| {
+        ParseError::at(
+            input_contents,
+            &disk_map[i..],
+            ParseErrorKind::InvalidDigit(char),
+        )
+    })
+}
+
+fn blocks(input_contents: &str) -> Result<Vec<BlockEntry>, ParseError> {
+    let mut blocks = vec![];
+    let disk_map = input_contents.trim();
+    let mut chars = disk_map.char_indices().peekable();
+
+    while let Some((i, file_blocks_amount_char)) = chars.next() {
+        let file_id = (i / 2) as i64;
+
+        for _ in 0..blocks_amount(input_contents, disk_map, i, file_blocks_amount_char)? {
+            blocks.push(BlockEntry::FileId(file_id));
+        }
+
+        if let Some(&(i, free_space_amount_char)) = chars.peek() {
+            for _ in 0..blocks_amount(input_contents, disk_map, i, free_space_amount_char)? {
+                blocks.push(BlockEntry::FreeSpace)
+            }
+
+            chars.next();
+        } else {
+            break;
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// Moves file blocks one at a time from the end of the disk to the leftmost free block, dropping
+/// the trailing free blocks.
+pub fn compact_file_blocks(blocks: &mut Vec<BlockEntry>) {
+    for i in 0..blocks.len() {
+        match blocks.get(i) {
+            None => break,
+            Some(BlockEntry::FreeSpace) => {
+                while let Some(last_block_entry) = blocks.pop() {
+                    if let BlockEntry::FileId(_) = last_block_entry {
+                        if blocks.get(i).is_some() {
+                            blocks[i] = last_block_entry;
+                        } else {
+                            blocks.push(last_block_entry);
+                        }
+
+                        break;
+                    }
+                }
+            }
+            _ => (),
+        };
+    }
+}
+
+/// Sums each file block position multiplied by its file id.
+pub fn checksum(blocks: &[BlockEntry]) -> i64 {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| match v {
+            BlockEntry::FileId(id) => Some((i as i64) * id),
+            _ => None,
+        })
+        .sum::<i64>()
+}
+
+fn checksum_after_file_blocks_compacting(blocks: &[BlockEntry]) -> i64 {
+    let mut blocks = blocks.to_vec();
+
+    compact_file_blocks(&mut blocks);
+    checksum(&blocks)
+}
+
+fn files(blocks: &[BlockEntry]) -> Vec<File> {
+    let mut iter = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| match v {
+            BlockEntry::FileId(file_id) => Some((i, *file_id)),
+            _ => None,
+        })
+        .rev()
+        .peekable();
+    let mut files = vec![];
+    let mut size = 1;
+
+    while let Some((i, file_id)) = iter.next() {
+        match iter.peek() {
+            Some((_, peek_file_id)) if file_id == *peek_file_id => size += 1,
+            _ => {
+                files.push(File {
+                    size,
+                    start_index: i,
+                });
+                size = 1;
+            }
+        }
+    }
+
+    files
+}
+
+fn free_spaces_suitable_for_compacting_start_index(
+    blocks: &[BlockEntry],
+    file: &File,
+) -> Option<usize> {
+    let size = file.size;
+    let mut count = 0;
+    let mut start_index = None;
+
+    for (i, v) in blocks.iter().enumerate() {
+        if let BlockEntry::FreeSpace = v {
+            if start_index.is_none() {
+                start_index = Some(i);
+            }
+
+            count += 1;
+
+            if count == size {
+                return start_index;
+            }
+        } else {
+            count = 0;
+            start_index = None;
+        }
+    }
+
+    None
+}
+
+/// Moves whole files, in decreasing file id order, to the leftmost span of free blocks they fit in,
+/// if any is on their left.
+pub fn compact_files(blocks: &mut [BlockEntry]) {
+    let files = files(blocks);
+
+    for file in files {
+        let maybe_free_space_start_index =
+            free_spaces_suitable_for_compacting_start_index(blocks, &file);
+
+        if let Some(free_space_start_index) = maybe_free_space_start_index {
+            if file.start_index > free_space_start_index {
+                swap_file_blocks_with_free_spaces(blocks, &file, free_space_start_index);
+            }
+        }
+    }
+}
+
+fn swap_file_blocks_with_free_spaces(
+    blocks: &mut [BlockEntry],
+    file: &File,
+    free_space_index: usize,
+) {
+    let file_blocks_indexes = file.start_index..(file.start_index + file.size);
+    let free_spaces_indexes = free_space_index..(free_space_index + file.size);
+
+    for (file_block_index, free_space_index) in file_blocks_indexes.zip(free_spaces_indexes) {
+        blocks.swap(file_block_index, free_space_index);
+    }
+}
+
+fn checksum_after_files_compacting(blocks: &[BlockEntry]) -> i64 {
+    let mut blocks = blocks.to_vec();
+
+    compact_files(&mut blocks);
+    checksum(&blocks)
+}
+
+impl Solution for Day09 {
+    type Input = Vec<BlockEntry>;
+    type Error = ParseError;
+    type Answer = i64;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error> {
+        blocks(input_contents)
+    }
+
+    fn part_one(blocks: &Self::Input) -> Self::Answer {
+        checksum_after_file_blocks_compacting(blocks)
+    }
+
+    fn part_two(blocks: &Self::Input) -> Self::Answer {
+        checksum_after_files_compacting(blocks)
+    }
+}
+
+/// Computes the [`checksum`] after [`compact_file_blocks`].
+pub fn filesystem_checksum_after_file_blocks_compacting(
+    input_contents: &str,
+) -> Result<i64, ParseError> {
+    Ok(Day09::part_one(&blocks(input_contents)?))
+}
+
+/// Computes the [`checksum`] after [`compact_files`].
+pub fn filesystem_checksum_after_files_compacting(input_contents: &str) -> Result<i64, ParseError> {
+    Ok(Day09::part_two(&blocks(input_contents)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "2333133121414131402";
+
+    #[test]
+    fn test_filesystem_checksum_after_file_blocks_compacting() {
+        let expected = 1928;
+        let actual = filesystem_checksum_after_file_blocks_compacting(TEST_INPUT_CONTENTS).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_filesystem_checksum_after_files_compacting() {
+        let expected = 2858;
+        let actual = filesystem_checksum_after_files_compacting(TEST_INPUT_CONTENTS).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_filesystem_checksum_with_malformed_input() {
+        let expected = "line 1, column 4: invalid digit `x`";
+        let actual = filesystem_checksum_after_files_compacting("233x133121414131402")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_2024_09::{
+    checksum, compact_file_blocks, compact_files, filesystem_checksum_after_file_blocks_compacting,
+    filesystem_checksum_after_files_compacting, BlockEntry, Day09,
+};
+use aoc_common::{Part, Solution};
+
+const INPUT_CONTENTS: &str = "12345";
+
+fn layout(blocks: &[BlockEntry]) -> String {
+    blocks
+        .iter()
+        .map(|block| match block {
+            BlockEntry::FileId(id) => id.to_string(),
+            BlockEntry::FreeSpace => ".".to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_compacting() {
+    let blocks = Day09::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!("0..111....22222", layout(&blocks));
+
+    let mut file_blocks_compacted = blocks.clone();
+    compact_file_blocks(&mut file_blocks_compacted);
+    assert_eq!("022111222", layout(&file_blocks_compacted));
+    assert_eq!(60, checksum(&file_blocks_compacted));
+
+    let mut files_compacted = blocks.clone();
+    compact_files(&mut files_compacted);
+    assert_eq!("0..111....22222", layout(&files_compacted));
+}
+
+#[test]
+fn test_solution() {
+    let input = Day09::parse(INPUT_CONTENTS).unwrap();
+
+    assert_eq!(60, Day09::solve(&input, Part::One));
+    assert_eq!(132, Day09::solve(&input, Part::Two));
+    assert_eq!(
+        Ok(60),
+        filesystem_checksum_after_file_blocks_compacting(INPUT_CONTENTS)
+    );
+    assert_eq!(
+        Ok(132),
+        filesystem_checksum_after_files_compacting(INPUT_CONTENTS)
+    );
+}
@@ -0,0 +1,45 @@
+use aoc_common::{input, Part, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+/// Criterion's minimum, for days whose slowest part takes a good fraction of a second.
+const SLOW_DAY_SAMPLE_SIZE: usize = 10;
+
+/// Benchmarks parsing the real input and solving each part from the parsed value, under a
+/// `day NN` group so a single day can be selected with `cargo bench -p aoc -- 'day NN'`.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, sample_size: Option<usize>) {
+    let input_contents = input::read(day, None).unwrap();
+    let input = S::parse(&input_contents).unwrap();
+    let mut group = c.benchmark_group(format!("day {day:02}"));
+
+    if let Some(sample_size) = sample_size {
+        group.sample_size(sample_size);
+    }
+
+    group.bench_function("parse", |b| {
+        b.iter(|| S::parse(black_box(&input_contents)).unwrap())
+    });
+
+    for part in Part::ALL {
+        group.bench_function(format!("part {part}"), |b| {
+            b.iter(|| S::solve(black_box(&input), part))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    bench_day::<aoc_2024_01::Day01>(c, 1, None);
+    bench_day::<aoc_2024_02::Day02>(c, 2, None);
+    bench_day::<aoc_2024_03::Day03>(c, 3, None);
+    bench_day::<aoc_2024_04::Day04>(c, 4, None);
+    bench_day::<aoc_2024_05::Day05>(c, 5, None);
+    bench_day::<aoc_2024_06::Day06>(c, 6, Some(SLOW_DAY_SAMPLE_SIZE));
+    bench_day::<aoc_2024_07::Day07>(c, 7, Some(SLOW_DAY_SAMPLE_SIZE));
+    bench_day::<aoc_2024_08::Day08>(c, 8, None);
+    bench_day::<aoc_2024_09::Day09>(c, 9, Some(SLOW_DAY_SAMPLE_SIZE));
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
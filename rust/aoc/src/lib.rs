@@ -0,0 +1,77 @@
+use aoc_common::{Part, Solution};
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+
+pub type RunError = Box<dyn Error + Send + Sync>;
+
+pub struct Day {
+    pub number: u8,
+    run: fn(&str, &[Part]) -> Result<DayRun, RunError>,
+}
+
+pub struct DayRun {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub solve_elapsed: Duration,
+}
+
+pub static DAYS: [Day; 9] = [
+    Day::new::<aoc_2024_01::Day01>(1),
+    Day::new::<aoc_2024_02::Day02>(2),
+    Day::new::<aoc_2024_03::Day03>(3),
+    Day::new::<aoc_2024_04::Day04>(4),
+    Day::new::<aoc_2024_05::Day05>(5),
+    Day::new::<aoc_2024_06::Day06>(6),
+    Day::new::<aoc_2024_07::Day07>(7),
+    Day::new::<aoc_2024_08::Day08>(8),
+    Day::new::<aoc_2024_09::Day09>(9),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Parses `input_contents` once, then solves each of `parts`, timing both phases separately.
+pub fn run_solution<S: Solution>(input_contents: &str, parts: &[Part]) -> Result<DayRun, RunError> {
+    let parse_start = Instant::now();
+    let input = S::parse(input_contents)?;
+    let parse_elapsed = parse_start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let solve_start = Instant::now();
+            let answer = S::solve(&input, part).to_string();
+
+            PartRun {
+                part,
+                answer,
+                solve_elapsed: solve_start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayRun {
+        parse_elapsed,
+        parts,
+    })
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Self {
+        Self {
+            number,
+            run: run_solution::<S>,
+        }
+    }
+
+    pub fn run(&self, input_contents: &str, parts: &[Part]) -> Result<DayRun, RunError> {
+        (self.run)(input_contents, parts)
+    }
+}
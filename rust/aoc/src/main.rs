@@ -0,0 +1,235 @@
+use aoc::DayRun;
+use aoc_common::{input, Part};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::{env, panic, process::ExitCode};
+
+const USAGE: &str = "Usage: aoc run <day> [--part 1|2] [--input <path>|-] [--format text|json]";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: Option<String>,
+        format: Format,
+    },
+}
+
+#[derive(Serialize)]
+struct DayReport<'a> {
+    day: u8,
+    input_sha256: String,
+    parse_elapsed_ns: u128,
+    parts: Vec<PartReport<'a>>,
+}
+
+#[derive(Serialize)]
+struct PartReport<'a> {
+    part: u8,
+    answer: &'a str,
+    solve_elapsed_ns: u128,
+}
+
+fn parse_number(value: Option<String>, name: &str) -> Result<u8, String> {
+    let value = value.ok_or_else(|| format!("missing {name}"))?;
+
+    value
+        .parse::<u8>()
+        .map_err(|_| format!("invalid {name} `{value}`"))
+}
+
+fn parse_format(value: Option<String>) -> Result<Format, String> {
+    match value.as_deref() {
+        Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(value) => Err(format!("unknown format `{value}`")),
+        None => Err("missing format".to_string()),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => (),
+        Some(command) => return Err(format!("unknown command `{command}`")),
+        None => return Err("missing command".to_string()),
+    }
+
+    let day = parse_number(args.next(), "day")?;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = Some(parse_number(args.next(), "part")?),
+            "--input" => input = Some(args.next().ok_or("missing input path")?),
+            "--format" => format = parse_format(args.next())?,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(Command::Run {
+        day,
+        part,
+        input,
+        format,
+    })
+}
+
+fn print_text(day_number: u8, day_run: &DayRun) {
+    println!("Day {day_number:02} parse: {:?}", day_run.parse_elapsed);
+
+    for part_run in &day_run.parts {
+        println!(
+            "Day {day_number:02} part {}: {} ({:?})",
+            part_run.part, part_run.answer, part_run.solve_elapsed
+        );
+    }
+}
+
+fn json_report(day_number: u8, input_contents: &str, day_run: &DayRun) -> String {
+    let report = DayReport {
+        day: day_number,
+        input_sha256: format!("{:x}", Sha256::digest(input_contents)),
+        parse_elapsed_ns: day_run.parse_elapsed.as_nanos(),
+        parts: day_run
+            .parts
+            .iter()
+            .map(|part_run| PartReport {
+                part: part_run.part.number(),
+                answer: &part_run.answer,
+                solve_elapsed_ns: part_run.solve_elapsed.as_nanos(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_string(&report).unwrap()
+}
+
+fn run(
+    day_number: u8,
+    part: Option<u8>,
+    input_path: Option<&str>,
+    format: Format,
+) -> Result<(), String> {
+    let day = aoc::find_day(day_number).ok_or_else(|| format!("day {day_number} is missing"))?;
+    let parts = match part.map(Part::try_from) {
+        Some(Ok(part)) => vec![part],
+        Some(Err(part)) => return Err(format!("part {part} does not exist")),
+        None => Part::ALL.to_vec(),
+    };
+    let input_contents = input::read(day_number, input_path).map_err(|error| error.to_string())?;
+    let day_run = panic::catch_unwind(|| day.run(&input_contents, &parts))
+        .map_err(|_| format!("day {day_number:02} panicked"))?
+        .map_err(|error| format!("day {day_number:02} input is invalid: {error}"))?;
+
+    match format {
+        Format::Text => print_text(day_number, &day_run),
+        Format::Json => println!("{}", json_report(day_number, &input_contents, &day_run)),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = parse_args(env::args().skip(1))
+        .map_err(|error| format!("{error}\n{USAGE}"))
+        .and_then(
+            |Command::Run {
+                 day,
+                 part,
+                 input,
+                 format,
+             }| run(day, part, input.as_deref(), format),
+        );
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("aoc: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::PartRun;
+    use std::time::Duration;
+
+    fn args(args: &str) -> impl Iterator<Item = String> + '_ {
+        args.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let expected = Ok(Command::Run {
+            day: 6,
+            part: None,
+            input: None,
+            format: Format::Text,
+        });
+        let actual = parse_args(args("run 06"));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_args_with_part() {
+        let expected = Ok(Command::Run {
+            day: 1,
+            part: Some(2),
+            input: Some("-".to_string()),
+            format: Format::Json,
+        });
+        let actual = parse_args(args("run 1 --part 2 --input - --format json"));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(args("")).is_err());
+        assert!(parse_args(args("walk 1")).is_err());
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run x")).is_err());
+        assert!(parse_args(args("run 1 --part")).is_err());
+        assert!(parse_args(args("run 1 --input")).is_err());
+        assert!(parse_args(args("run 1 --verbose")).is_err());
+        assert!(parse_args(args("run 1 --format")).is_err());
+        assert!(parse_args(args("run 1 --format xml")).is_err());
+    }
+
+    #[test]
+    fn test_run_missing_day() {
+        let expected = Err("day 25 is missing".to_string());
+        let actual = run(25, None, None, Format::Text);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_json_report() {
+        let day_run = DayRun {
+            parse_elapsed: Duration::from_nanos(1500),
+            parts: vec![PartRun {
+                part: Part::Two,
+                answer: "31".to_string(),
+                solve_elapsed: Duration::from_micros(2),
+            }],
+        };
+        let expected = r#"{"day":1,"input_sha256":"2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824","parse_elapsed_ns":1500,"parts":[{"part":2,"answer":"31","solve_elapsed_ns":2000}]}"#;
+        let actual = json_report(1, "hello", &day_run);
+
+        assert_eq!(expected, actual);
+    }
+}
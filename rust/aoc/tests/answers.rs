@@ -0,0 +1,79 @@
+use aoc::DAYS;
+use aoc_common::{input, Part};
+use std::{fs, path::Path};
+
+const ANSWERS_RELATIVE_PATH: &str = "inputs/answers";
+
+#[derive(Debug)]
+struct RecordedAnswer {
+    day: u8,
+    part: Part,
+    answer: String,
+}
+
+fn recorded_answers() -> Vec<RecordedAnswer> {
+    let path = input::find(Path::new(ANSWERS_RELATIVE_PATH))
+        .unwrap_or_else(|| panic!("`{ANSWERS_RELATIVE_PATH}` not found"));
+    let contents = fs::read_to_string(&path).unwrap();
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, part, answer] = fields[..] else {
+                panic!(
+                    "{ANSWERS_RELATIVE_PATH}:{}: expected `day part answer`",
+                    i + 1
+                );
+            };
+
+            RecordedAnswer {
+                day: day.parse().unwrap(),
+                part: part.parse::<u8>().unwrap().try_into().unwrap(),
+                answer: answer.to_string(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_every_part_has_a_recorded_answer() {
+    let recorded_answers = recorded_answers();
+
+    for day in &DAYS {
+        for part in Part::ALL {
+            assert!(
+                recorded_answers
+                    .iter()
+                    .any(|answer| answer.day == day.number && answer.part == part),
+                "day {:02} part {part} has no recorded answer",
+                day.number
+            );
+        }
+    }
+}
+
+#[test]
+fn test_recorded_answers() {
+    let mut mismatches = vec![];
+
+    for recorded_answer in recorded_answers() {
+        let RecordedAnswer { day, part, answer } = recorded_answer;
+        let input_contents = input::read(day, None).unwrap();
+        let day_run = aoc::find_day(day)
+            .unwrap_or_else(|| panic!("day {day:02} is missing"))
+            .run(&input_contents, &[part])
+            .unwrap();
+        let actual = &day_run.parts[0].answer;
+
+        if *actual != answer {
+            mismatches.push(format!(
+                "day {day:02} part {part}: expected {answer}, got {actual}"
+            ));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
@@ -0,0 +1,168 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+/// Malformed puzzle input, located by its 1-based line and column.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    Empty,
+    InvalidInteger {
+        token: String,
+        source: ParseIntError,
+    },
+    InvalidDigit(char),
+    MissingSeparator(&'static str),
+    MissingValue(&'static str),
+    MissingTile(char),
+    UnevenRow {
+        expected: usize,
+        actual: usize,
+    },
+    ColumnCount {
+        expected: usize,
+        actual: usize,
+    },
+    TooFewValues {
+        min: usize,
+        actual: usize,
+    },
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+
+    /// Locates the error at the byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, kind: ParseErrorKind) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+
+        Self::new(line, column, kind)
+    }
+
+    /// Locates the error at the start of `fragment`, which must be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, kind: ParseErrorKind) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("fragment must be a slice of input");
+
+        Self::at_offset(input, offset, kind)
+    }
+
+    /// Locates the error right after the last character of `input`, for missing values.
+    pub fn at_end(input: &str, kind: ParseErrorKind) -> Self {
+        Self::at_offset(input, input.len(), kind)
+    }
+}
+
+/// Parses `token`, a slice of `input`, as an integer, locating the error on failure.
+pub fn parse_integer<T>(input: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    token.parse::<T>().map_err(|source| {
+        let kind = ParseErrorKind::InvalidInteger {
+            token: token.to_string(),
+            source,
+        };
+
+        ParseError::at(input, token, kind)
+    })
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "input is empty"),
+            Self::InvalidInteger { token, source } => {
+                write!(f, "invalid integer `{token}`: {source}")
+            }
+            Self::InvalidDigit(char) => write!(f, "invalid digit `{char}`"),
+            Self::MissingSeparator(separator) => write!(f, "missing {separator} separator"),
+            Self::MissingValue(value) => write!(f, "missing {value}"),
+            Self::MissingTile(tile) => write!(f, "missing `{tile}` tile"),
+            Self::UnevenRow { expected, actual } => write!(
+                f,
+                "row has {actual} cells, expected {expected} like the first row"
+            ),
+            Self::ColumnCount { expected, actual } => {
+                write!(f, "line has {actual} columns, expected {expected}")
+            }
+            Self::TooFewValues { min, actual } => {
+                write!(f, "line has {actual} values, expected at least {min}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidInteger { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "12 34
+56 7x
+";
+
+    #[test]
+    fn test_at() {
+        let token = &TEST_INPUT_CONTENTS[9..11];
+        let error = ParseError::at(
+            TEST_INPUT_CONTENTS,
+            token,
+            ParseErrorKind::InvalidDigit('x'),
+        );
+
+        assert_eq!((2, 4), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_at_end() {
+        let error = ParseError::at_end("ab\ncd", ParseErrorKind::MissingTile('^'));
+
+        assert_eq!((2, 3), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_parse_integer() {
+        let token = &TEST_INPUT_CONTENTS[9..11];
+
+        assert_eq!(
+            Ok(12),
+            parse_integer::<i32>(TEST_INPUT_CONTENTS, &TEST_INPUT_CONTENTS[..2])
+        );
+        assert_eq!(
+            "line 2, column 4: invalid integer `7x`: invalid digit found in string",
+            parse_integer::<i32>(TEST_INPUT_CONTENTS, token)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
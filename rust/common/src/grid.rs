@@ -0,0 +1,472 @@
+use crate::{ParseError, ParseErrorKind};
+use std::{
+    ops::{Index, IndexMut, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// A cell position inside a [`Grid`], zero-based from the top-left corner.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A signed displacement between two [`Coord`]s.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Offset {
+    pub row: isize,
+    pub col: isize,
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+/// Cells visited by walking a [`Grid`] from a starting coordinate in a fixed direction.
+pub struct Line<'a, T> {
+    grid: &'a Grid<T>,
+    next: Option<Coord>,
+    direction: Direction,
+}
+
+impl Coord {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Returns the coordinate moved by `offset`, or `None` if it would become negative.
+    pub fn offset(self, offset: Offset) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(offset.row)?,
+            col: self.col.checked_add_signed(offset.col)?,
+        })
+    }
+}
+
+impl Sub for Coord {
+    type Output = Offset;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Offset::new(
+            self.row as isize - other.row as isize,
+            self.col as isize - other.col as isize,
+        )
+    }
+}
+
+impl Offset {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self::Output {
+        Self::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+impl Direction {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+    pub const DIAGONAL: [Self; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    pub const fn offset(self) -> Offset {
+        match self {
+            Self::Up => Offset::new(-1, 0),
+            Self::UpRight => Offset::new(-1, 1),
+            Self::Right => Offset::new(0, 1),
+            Self::DownRight => Offset::new(1, 1),
+            Self::Down => Offset::new(1, 0),
+            Self::DownLeft => Offset::new(1, -1),
+            Self::Left => Offset::new(0, -1),
+            Self::UpLeft => Offset::new(-1, -1),
+        }
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::UpRight => Self::DownRight,
+            Self::Right => Self::Down,
+            Self::DownRight => Self::DownLeft,
+            Self::Down => Self::Left,
+            Self::DownLeft => Self::UpLeft,
+            Self::Left => Self::Up,
+            Self::UpLeft => Self::UpRight,
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per line and one cell per character, mapping each character with `tile`.
+    pub fn parse_with(input: &str, mut tile: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut rows = 0;
+        let mut cols = None;
+
+        for (i, line) in input.lines().enumerate() {
+            let cells_before = cells.len();
+
+            cells.extend(line.chars().map(&mut tile));
+
+            let actual = cells.len() - cells_before;
+
+            match cols {
+                None => cols = Some(actual),
+                Some(expected) if expected != actual => {
+                    return Err(ParseError::new(
+                        i + 1,
+                        expected.min(actual) + 1,
+                        ParseErrorKind::UnevenRow { expected, actual },
+                    ))
+                }
+                _ => (),
+            }
+
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self { cells, rows, cols }),
+            _ => Err(ParseError::new(1, 1, ParseErrorKind::Empty)),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.rows && coord.col < self.cols
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[self.cell_index(coord)])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if !self.contains(coord) {
+            return None;
+        }
+
+        let index = self.cell_index(coord);
+
+        Some(&mut self.cells[index])
+    }
+
+    /// Returns `coord` moved by `offset` if the result is still inside the grid.
+    pub fn offset(&self, coord: Coord, offset: Offset) -> Option<Coord> {
+        coord.offset(offset).filter(|&coord| self.contains(coord))
+    }
+
+    /// Returns the adjacent coordinate in `direction` if it is inside the grid.
+    pub fn neighbor(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        self.offset(coord, direction.offset())
+    }
+
+    /// Returns every in-bound coordinate among the eight surrounding `coord`.
+    pub fn neighbors(&self, coord: Coord) -> impl Iterator<Item = (Direction, Coord)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.neighbor(coord, direction)?)))
+    }
+
+    /// Returns every coordinate in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let cols = self.cols;
+
+        (0..self.cells.len()).map(move |i| Coord::new(i / cols, i % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.iter()
+            .find_map(|(coord, tile)| predicate(tile).then_some(coord))
+    }
+
+    /// Walks from `start` in `direction` until leaving the grid.
+    pub fn line(&self, start: Coord, direction: Direction) -> Line<'_, T> {
+        Line {
+            grid: self,
+            next: self.contains(start).then_some(start),
+            direction,
+        }
+    }
+
+    pub fn row(&self, row: usize) -> Line<'_, T> {
+        self.line(Coord::new(row, 0), Direction::Right)
+    }
+
+    pub fn column(&self, col: usize) -> Line<'_, T> {
+        self.line(Coord::new(0, col), Direction::Down)
+    }
+
+    /// Returns every row, from top to bottom.
+    pub fn all_rows(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    /// Returns every column, from left to right.
+    pub fn all_columns(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Returns every top-left to bottom-right diagonal, starting from the bottom-left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let left_column_starts = (1..self.rows).rev().map(|row| Coord::new(row, 0));
+        let top_row_starts = (0..self.cols).map(|col| Coord::new(0, col));
+
+        left_column_starts
+            .chain(top_row_starts)
+            .map(|start| self.line(start, Direction::DownRight))
+    }
+
+    /// Returns every top-right to bottom-left diagonal, starting from the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let top_row_starts = (0..self.cols).map(|col| Coord::new(0, col));
+        let right_column_starts = (1..self.rows).map(|row| Coord::new(row, self.cols - 1));
+
+        top_row_starts
+            .chain(right_column_starts)
+            .map(|start| self.line(start, Direction::DownLeft))
+    }
+
+    fn cell_index(&self, coord: Coord) -> usize {
+        coord.row * self.cols + coord.col
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, |char| char)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        match self.get(coord) {
+            Some(tile) => tile,
+            None => panic!(
+                "coordinate {coord:?} out of bounds for a {}x{} grid",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+
+        match self.get_mut(coord) {
+            Some(tile) => tile,
+            None => panic!("coordinate {coord:?} out of bounds for a {rows}x{cols} grid"),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Line<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coord = self.next?;
+
+        self.next = self.grid.neighbor(coord, self.direction);
+
+        Some(&self.grid[coord])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_CONTENTS: &str = "abc
+def
+ghi
+jkl
+";
+
+    fn collect_lines<'a>(lines: impl Iterator<Item = Line<'a, char>>) -> Vec<String> {
+        lines.map(String::from_iter).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(4, grid.rows());
+        assert_eq!(3, grid.cols());
+        assert_eq!('f', grid[Coord::new(1, 2)]);
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let expected = Some(&7);
+        let grid = Grid::parse_with("123\n456\n789\n", |char| char.to_digit(10).unwrap()).unwrap();
+        let actual = grid.get(Coord::new(2, 0));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_uneven_row() {
+        let expected = Err(ParseError::new(
+            3,
+            3,
+            ParseErrorKind::UnevenRow {
+                expected: 3,
+                actual: 2,
+            },
+        ));
+        let actual = "abc\ndef\ngh\n".parse::<Grid<char>>();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let expected = Err(ParseError::new(1, 1, ParseErrorKind::Empty));
+
+        assert_eq!(expected, "".parse::<Grid<char>>());
+        assert_eq!(expected, "\n\n".parse::<Grid<char>>());
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(None, grid.get(Coord::new(4, 0)));
+        assert_eq!(None, grid.get(Coord::new(0, 3)));
+    }
+
+    #[test]
+    fn test_neighbor() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(
+            Some(Coord::new(0, 1)),
+            grid.neighbor(Coord::new(1, 1), Direction::Up)
+        );
+        assert_eq!(
+            Some(Coord::new(2, 0)),
+            grid.neighbor(Coord::new(1, 1), Direction::DownLeft)
+        );
+        assert_eq!(None, grid.neighbor(Coord::new(0, 1), Direction::UpRight));
+        assert_eq!(None, grid.neighbor(Coord::new(3, 2), Direction::Right));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(3, grid.neighbors(Coord::new(0, 0)).count());
+        assert_eq!(5, grid.neighbors(Coord::new(1, 0)).count());
+        assert_eq!(8, grid.neighbors(Coord::new(1, 1)).count());
+    }
+
+    #[test]
+    fn test_offset() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+        let (a, b) = (Coord::new(0, 0), Coord::new(1, 1));
+
+        assert_eq!(Some(Coord::new(2, 2)), grid.offset(b, b - a));
+        assert_eq!(None, grid.offset(b, (b - a) * 2));
+        assert_eq!(None, grid.offset(a, -(b - a)));
+    }
+
+    #[test]
+    fn test_position() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(Some(Coord::new(2, 1)), grid.position(|&char| char == 'h'));
+        assert_eq!(None, grid.position(|&char| char == 'z'));
+    }
+
+    #[test]
+    fn test_all_rows_and_columns() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(
+            vec!["abc", "def", "ghi", "jkl"],
+            collect_lines(grid.all_rows())
+        );
+        assert_eq!(
+            vec!["adgj", "behk", "cfil"],
+            collect_lines(grid.all_columns())
+        );
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = TEST_INPUT_CONTENTS.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(
+            vec!["j", "gk", "dhl", "aei", "bf", "c"],
+            collect_lines(grid.diagonals())
+        );
+        assert_eq!(
+            vec!["a", "bd", "ceg", "fhj", "ik", "l"],
+            collect_lines(grid.anti_diagonals())
+        );
+    }
+
+    #[test]
+    fn test_direction_turn_right() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Up, Direction::Left.turn_right());
+        assert_eq!(Direction::DownLeft, Direction::UpRight.opposite());
+    }
+}
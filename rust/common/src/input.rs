@@ -0,0 +1,151 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Input path argument meaning "read the puzzle input from stdin".
+pub const STDIN_ARG: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { relative_path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+/// Returns the conventional `inputs/NN/input` location relative to the repository root.
+pub fn relative_path(day: u8) -> PathBuf {
+    Path::new("inputs").join(format!("{day:02}")).join("input")
+}
+
+/// Looks for `relative_path` in the current directory and then in each of its parents, so
+/// binaries, tests and benchmarks find it whether they run from the repository root, the
+/// workspace or a day crate.
+pub fn find(relative_path: &Path) -> Option<PathBuf> {
+    let current_dir = env::current_dir().ok()?;
+
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join(relative_path))
+        .find(|path| path.is_file())
+}
+
+/// Looks for `inputs/NN/input` like [`find`] does.
+pub fn default_path(day: u8) -> Option<PathBuf> {
+    find(&relative_path(day))
+}
+
+/// Reads the puzzle input from `path`, from stdin when `path` is [`STDIN_ARG`], or from the
+/// [`default_path`] when no path is given.
+pub fn read(day: u8, path: Option<&str>) -> Result<String, InputError> {
+    let path = match path {
+        Some(STDIN_ARG) => {
+            let mut input_contents = String::new();
+
+            io::stdin()
+                .read_to_string(&mut input_contents)
+                .map_err(InputError::Stdin)?;
+
+            return Ok(input_contents);
+        }
+        Some(path) => PathBuf::from(path),
+        None => default_path(day).ok_or_else(|| InputError::NotFound {
+            relative_path: relative_path(day),
+        })?,
+    };
+
+    fs::read_to_string(&path).map_err(|source| InputError::Unreadable { path, source })
+}
+
+/// Reads the puzzle input from the path given as first command line argument.
+pub fn read_from_args(day: u8) -> Result<String, InputError> {
+    read(day, env::args().nth(1).as_deref())
+}
+
+/// Removes `flag` from the command line `args`, returning whether it was there.
+pub fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|arg| arg == flag) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { relative_path } => write!(
+                f,
+                "input file `{}` not found in the current directory or any of its parents, \
+                 pass its path or `{STDIN_ARG}` to read it from stdin",
+                relative_path.display()
+            ),
+            Self::Unreadable { path, source } => {
+                write!(f, "cannot read input file `{}`: {source}", path.display())
+            }
+            Self::Stdin(source) => write!(f, "cannot read input from stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::Unreadable { source, .. } | Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_path() {
+        let expected = Path::new("inputs/06/input");
+        let actual = relative_path(6);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_default_path() {
+        let path = default_path(1).unwrap();
+
+        assert!(path.ends_with("inputs/01/input"));
+    }
+
+    #[test]
+    fn test_read_default() {
+        assert!(!read(1, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = read(1, Some("inputs/01/missing")).unwrap_err();
+
+        assert!(matches!(error, InputError::Unreadable { .. }));
+        assert!(error.to_string().contains("inputs/01/missing"));
+    }
+
+    #[test]
+    fn test_read_missing_default() {
+        let error = read(99, None).unwrap_err();
+
+        assert!(matches!(error, InputError::NotFound { .. }));
+        assert!(error.to_string().contains("inputs/99/input"));
+    }
+
+    #[test]
+    fn test_take_flag() {
+        let mut args = vec!["--audit".to_string(), "input".to_string()];
+
+        assert!(take_flag(&mut args, "--audit"));
+        assert!(!take_flag(&mut args, "--audit"));
+        assert_eq!(vec!["input".to_string()], args);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod solution;
+
+pub use error::{ParseError, ParseErrorKind};
+pub use solution::{Part, Solution};
@@ -0,0 +1,56 @@
+use std::{error::Error, fmt};
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// A day's puzzle, split into parsing the input once and solving both parts from the parsed value.
+pub trait Solution {
+    type Input;
+    type Error: Error + Send + Sync + 'static;
+    type Answer: fmt::Display;
+
+    fn parse(input_contents: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part_one(input: &Self::Input) -> Self::Answer;
+
+    fn part_two(input: &Self::Input) -> Self::Answer;
+
+    fn solve(input: &Self::Input, part: Part) -> Self::Answer {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => Err(number),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}